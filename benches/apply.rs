@@ -0,0 +1,47 @@
+//! Compares `apply` with the closure returned by `compile`.
+//!
+//! Run with: cargo bench --bench apply
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use mobius_applicatio::MobiusTransform;
+use num_complex::Complex64;
+
+const POINTS: usize = 1 << 16;
+
+fn sample_points() -> Vec<Complex64> {
+    (0..POINTS)
+        .map(|k| Complex64::from_polar(1e-3 * k as f64, 0.61 * k as f64))
+        .collect()
+}
+
+fn bench_apply(c: &mut Criterion) {
+    let transform = MobiusTransform::new(
+        Complex64::new(2.0, 1.0),
+        Complex64::new(1.0, 0.0),
+        Complex64::new(1.0, 1.0),
+        Complex64::new(3.0, 0.0),
+    ).expect("Valid transform coefficients");
+    let points = sample_points();
+
+    c.bench_function("apply", |b| {
+        b.iter(|| {
+            points
+                .iter()
+                .map(|&z| transform.apply(black_box(z)))
+                .fold(Complex64::new(0.0, 0.0), |acc, w| acc + w)
+        })
+    });
+
+    let compiled = transform.compile();
+    c.bench_function("compiled", |b| {
+        b.iter(|| {
+            points
+                .iter()
+                .map(|&z| compiled(black_box(z)))
+                .fold(Complex64::new(0.0, 0.0), |acc, w| acc + w)
+        })
+    });
+}
+
+criterion_group!(benches, bench_apply);
+criterion_main!(benches);
@@ -5,14 +5,16 @@
 use eframe::egui::{self, ColorImage};
 use egui_plot::{Line, Plot, PlotImage, PlotPoints};
 use num_complex::Complex;
-use eframe::egui::Color32;
-use mobius_applicatio::{MobiusTransform, plane_functions};
+use mobius_applicatio::MobiusTransform;
+use mobius_applicatio::plane_functions::{self, GridConfig, GridHit};
+use mobius_applicatio::render;
 
 // Window and plot sizing
 const WINDOW_SIZE: f32 = 1280.0;
 const PLOT_SIZE: f32 = 1200.0;
 const IMAGE_RESOLUTION: usize = 1280;
 const PLANE_RANGE: f64 = 2.0;  // Complex plane spans from -PLANE_RANGE to +PLANE_RANGE
+const SAMPLES_PER_PIXEL: usize = 3;  // Supersampling factor per axis for smooth edges
 
 fn main() -> eframe::Result<()> {
     let options = eframe::NativeOptions {
@@ -39,14 +41,7 @@ impl eframe::App for ComplexPlaneApp {
             
             // Generate the color-mapped image if not already created
             if self.image_texture.is_none() {
-                let image = self.generate_color_image(
-                    IMAGE_RESOLUTION, 
-                    IMAGE_RESOLUTION, 
-                    -PLANE_RANGE, 
-                    PLANE_RANGE, 
-                    -PLANE_RANGE, 
-                    PLANE_RANGE
-                );
+                let image = self.generate_color_image(IMAGE_RESOLUTION, PLANE_RANGE);
                 self.image_texture = Some(ui.ctx().load_texture(
                     "complex_plane_colors",
                     image,
@@ -101,69 +96,30 @@ impl eframe::App for ComplexPlaneApp {
 
 impl ComplexPlaneApp {
     /// Generate a color image by sampling the complex plane
-    fn generate_color_image(
-        &self,
-        width: usize,
-        height: usize,
-        x_min: f64,
-        x_max: f64,
-        y_min: f64,
-        y_max: f64,
-    ) -> ColorImage {
-        let mut pixels = Vec::with_capacity(width * height);
-        
-        for row in 0..height {
-            for col in 0..width {
-                // Map pixel coordinates to complex plane coordinates
-                let real = x_min + (col as f64 / width as f64) * (x_max - x_min);
-                // Note: flip y-axis (screen coords go down, imaginary axis goes up)
-                let imag = y_max - (row as f64 / height as f64) * (y_max - y_min);
-                
-                let z = Complex::new(real, imag);
-                
-                // Use the point_color function to determine color
-                let color = match point_color(z) {
-                    Some(c) => c,
-                    None => Color32::TRANSPARENT, // Handle transparent pixels
-                };
-                
-                pixels.push(color);
-            }
-        }
-        
-        ColorImage {
-            size: [width, height],
-            pixels,
-        }
+    fn generate_color_image(&self, size: usize, range: f64) -> ColorImage {
+        // Apply Möbius transform
+        let transform = MobiusTransform::new(
+            Complex::new(1.0, 0.0),  // a
+            Complex::new(-1.0, 0.0),  // b
+            Complex::new(1.0, 0.0),  // c
+            Complex::new(1.0, 0.0),  // d
+        ).expect("Valid transform coefficients");
+        // let transform = MobiusTransform::identity();
+
+        let buffer = render::render_supersampled(&transform, size, size, range, SAMPLES_PER_PIXEL, point_color);
+        ColorImage::from_rgba_unmultiplied([size, size], &buffer)
     }
 }
 
-/// Determine the color for a point in the complex plane.
-/// Returns None for transparent (no color) pixels.
-fn point_color(z: Complex<f64>) -> Option<Color32> {
-    // Apply Möbius transform
-    let transform = MobiusTransform::new(
-        Complex::new(1.0, 0.0),  // a
-        Complex::new(-1.0, 0.0),  // b
-        Complex::new(1.0, 0.0),  // c
-        Complex::new(1.0, 0.0),  // d
-    ).expect("Valid transform coefficients");
-    // let transform = MobiusTransform::identity();
-    let z = transform.apply(z);
-    
-    // Test each grid type in order, return first match with its color
-    if plane_functions::vertical_grid(z, 0.2, 0.01) {
-        return Some(Color32::from_rgb(255, 0, 0)); // Red vertical bars
-    }
-    if plane_functions::horizontal_grid(z, 0.2, 0.01) {
-        return Some(Color32::from_rgb(0, 0, 255)); // Blue horizontal bars
+/// Determine the RGBA color for a transformed point in the complex plane.
+/// Returns fully transparent pixels off the grid.
+fn point_color(z: Complex<f64>) -> [u8; 4] {
+    // Color by the first grid the point lies on
+    match plane_functions::classify_grid(z, &GridConfig::default()) {
+        GridHit::Vertical => [255, 0, 0, 255], // Red vertical bars
+        GridHit::Horizontal => [0, 0, 255, 255], // Blue horizontal bars
+        GridHit::Radial => [0, 255, 0, 255], // Green circles
+        GridHit::Angular => [255, 0, 255, 255], // Magenta angular lines
+        GridHit::None => [0, 0, 0, 0],
     }
-    if plane_functions::radial_grid(z, 0.2, 0.01) {
-        return Some(Color32::from_rgb(0, 255, 0)); // Green circles
-    }
-    if plane_functions::angular_grid(z, std::f64::consts::PI / 12.0, 0.01) {
-        return Some(Color32::from_rgb(255, 0, 255)); // Magenta angular lines
-    }
-    
-    None
-}
\ No newline at end of file
+}
@@ -0,0 +1,285 @@
+//! Anti-Möbius transformation module.
+//!
+//! Provides the [`AntiMobiusTransform`] type for orientation-reversing conformal
+//! maps of the form: f(z) = (a z̄ + b) / (c z̄ + d)
+
+use num_complex::Complex64;
+use crate::transforms::{MobiusTransform, TransformError};
+
+/// Represents an anti-Möbius (anti-holomorphic) transformation.
+///
+/// The transformation is defined as: f(z) = (a z̄ + b) / (c z̄ + d)
+/// where ad - bc ≠ 0, i.e. a Möbius transformation applied to the complex conjugate
+/// of its input. Reflections in lines and inversions in circles are anti-Möbius
+/// transformations, as are glide reflections.
+///
+/// Composing two anti-Möbius transformations gives an ordinary [`MobiusTransform`].
+#[derive(Debug, Clone, Copy)]
+pub struct AntiMobiusTransform {
+    /// The Möbius part M, with f(z) = M(z̄)
+    mobius: MobiusTransform,
+}
+
+impl AntiMobiusTransform {
+    /// Creates a new anti-Möbius transformation.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InfiniteCoefficient` if any coefficient is infinite or NaN.
+    /// Returns `TransformError::SingularTransform` if the determinant (ad - bc) is zero.
+    pub fn new(a: Complex64, b: Complex64, c: Complex64, d: Complex64) -> Result<Self, TransformError> {
+        Ok(Self::from_mobius(MobiusTransform::new(a, b, c, d)?))
+    }
+
+    /// Creates the anti-Möbius transformation z ↦ m(z̄).
+    pub fn from_mobius(mobius: MobiusTransform) -> Self {
+        Self { mobius }
+    }
+
+    /// Creates complex conjugation z ↦ z̄, the reflection in the real axis.
+    pub fn conjugation() -> Self {
+        Self::from_mobius(MobiusTransform::identity())
+    }
+
+    /// Creates the inversion in the circle with the given center p and radius r,
+    /// z ↦ p + r² / (z̄ - p̄).
+    ///
+    /// Each point z ≠ p is sent to the point on the ray from p through z at distance
+    /// r² / |z - p| from p, so the circle is fixed pointwise, its inside and outside
+    /// are exchanged, and p and infinity are swapped. The inversion is an involution.
+    /// Center 0 and radius 1 give z ↦ 1/z̄.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InvalidArgument` if the radius is not a positive
+    /// finite number.
+    /// Returns `TransformError::InfiniteCoefficient` if the center is infinite or NaN,
+    /// or r² overflows.
+    /// Returns `TransformError::SingularTransform` if r² is lost to rounding, i.e. it
+    /// underflows or is below about 10⁻¹⁶ |p|².
+    pub fn circle_inversion(center: Complex64, radius: f64) -> Result<Self, TransformError> {
+        if !radius.is_finite() || radius <= 0.0 {
+            return Err(TransformError::InvalidArgument(
+                "inversion radius must be positive and finite".to_string(),
+            ));
+        }
+        // (p z̄ + r² - |p|²) / (z̄ - p̄), with determinant -r². The inversion is exactly
+        // invertible for every r > 0, so only a zero determinant is rejected
+        let mobius = MobiusTransform::new_with_tolerance(
+            center,
+            Complex64::new(radius * radius - center.norm_sqr(), 0.0),
+            Complex64::new(1.0, 0.0),
+            -center.conj(),
+            0.0,
+        )?;
+        Ok(Self::from_mobius(mobius))
+    }
+
+    /// Returns the Möbius part m of the transformation z ↦ m(z̄).
+    pub fn mobius_part(&self) -> MobiusTransform {
+        self.mobius
+    }
+
+    /// Applies the transformation to a complex number.
+    ///
+    /// The point at infinity is handled as in [`MobiusTransform::apply`], since the
+    /// conjugate of infinity is infinity.
+    pub fn apply(&self, z: Complex64) -> Complex64 {
+        self.mobius.apply(z.conj())
+    }
+
+    /// Composes this transformation with another: (self ∘ other)(z) = self(other(z))
+    ///
+    /// Two orientation reversals preserve orientation, so the result is an ordinary
+    /// Möbius transformation: if self = M₁(z̄) and other = M₂(z̄), then
+    /// self ∘ other = M₁ ∘ M̄₂, where M̄₂ has conjugated coefficients.
+    pub fn compose(&self, other: &AntiMobiusTransform) -> MobiusTransform {
+        self.mobius.compose(&other.mobius.conj())
+    }
+
+    /// Returns the inverse transformation.
+    ///
+    /// If f(z) = M(z̄) then f⁻¹(w) = conj(M⁻¹(w)) = M̄⁻¹(w̄), which is again anti-Möbius.
+    pub fn inverse(&self) -> AntiMobiusTransform {
+        Self::from_mobius(self.mobius.inverse().conj())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex_utils::{is_infinity, COMPLEX_INFINITY};
+
+    /// Inversion in the unit circle, z ↦ 1/z̄.
+    fn unit_circle_inversion() -> AntiMobiusTransform {
+        AntiMobiusTransform::new(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap()
+    }
+
+    #[test]
+    fn test_apply() {
+        let conjugation = AntiMobiusTransform::conjugation();
+        assert_eq!(conjugation.apply(Complex64::new(1.0, 2.0)), Complex64::new(1.0, -2.0));
+
+        // Inversion in the unit circle keeps the argument and inverts the modulus
+        let inversion = unit_circle_inversion();
+        let result = inversion.apply(Complex64::new(0.0, 2.0));
+        assert!((result - Complex64::new(0.0, 0.5)).norm() < 1e-10);
+        assert!(is_infinity(inversion.apply(Complex64::new(0.0, 0.0))));
+        assert_eq!(inversion.apply(COMPLEX_INFINITY), Complex64::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_compose_reflections_is_mobius() {
+        // Reflecting in the real axis then inverting in the unit circle gives 1/z
+        let composed = unit_circle_inversion().compose(&AntiMobiusTransform::conjugation());
+        let expected = MobiusTransform::new(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap();
+        assert!(composed.approx_eq(&expected));
+
+        // Reflections in two lines through 0 at angle α compose to a rotation by 2α
+        let alpha = 0.4;
+        let line_reflection = AntiMobiusTransform::new(
+            Complex64::from_polar(1.0, 2.0 * alpha),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        let rotation = line_reflection.compose(&AntiMobiusTransform::conjugation());
+        let z = Complex64::new(1.0, 0.0);
+        assert!((rotation.apply(z) - Complex64::from_polar(1.0, 2.0 * alpha)).norm() < 1e-10);
+    }
+
+    #[test]
+    fn test_reflection_twice_is_identity() {
+        // Reflection in the line through p at angle α: z ↦ e^{2iα}(z̄ - p̄) + p
+        let p = Complex64::new(0.5, -1.0);
+        let rotation = Complex64::from_polar(1.0, 0.8);
+        let line_reflection = AntiMobiusTransform::new(
+            rotation,
+            p - rotation * p.conj(),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+
+        let z = Complex64::new(0.3, -0.7);
+        for reflection in [AntiMobiusTransform::conjugation(), unit_circle_inversion(), line_reflection] {
+            assert!(reflection.compose(&reflection).approx_eq(&MobiusTransform::identity()));
+            assert!((reflection.apply(reflection.apply(z)) - z).norm() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_circle_inversion() {
+        let center = Complex64::new(1.0, -2.0);
+        let radius = 1.5;
+        let inversion = AntiMobiusTransform::circle_inversion(center, radius).unwrap();
+
+        // Points on the circle are fixed
+        for k in 0..8 {
+            let z = center + Complex64::from_polar(radius, 0.8 * k as f64);
+            assert!((inversion.apply(z) - z).norm() < 1e-12);
+        }
+
+        // Other points move along the ray from the center, and applying twice is the identity
+        let z = center + Complex64::from_polar(0.5, 0.3);
+        let image = inversion.apply(z);
+        assert!((image - (center + Complex64::from_polar(4.5, 0.3))).norm() < 1e-12);
+        assert!((inversion.apply(image) - z).norm() < 1e-12);
+        assert!(inversion.compose(&inversion).approx_eq(&MobiusTransform::identity()));
+        assert!(is_infinity(inversion.apply(center)));
+        assert!((inversion.apply(COMPLEX_INFINITY) - center).norm() < 1e-12);
+
+        // The unit circle gives z ↦ 1/z̄
+        let unit = AntiMobiusTransform::circle_inversion(Complex64::new(0.0, 0.0), 1.0).unwrap();
+        assert!(unit.mobius_part().approx_eq(&unit_circle_inversion().mobius_part()));
+    }
+
+    #[test]
+    fn test_circle_inversion_invalid() {
+        let center = Complex64::new(0.0, 0.0);
+        for radius in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                AntiMobiusTransform::circle_inversion(center, radius),
+                Err(TransformError::InvalidArgument(_))
+            ));
+        }
+        assert_eq!(
+            AntiMobiusTransform::circle_inversion(Complex64::new(f64::NAN, 0.0), 1.0).unwrap_err(),
+            TransformError::InfiniteCoefficient
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        let m = AntiMobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let z = Complex64::new(2.0, 3.0);
+        let result = m.inverse().apply(m.apply(z));
+        assert!((result - z).norm() < 1e-10);
+        assert!(m.inverse().compose(&m).approx_eq(&MobiusTransform::identity()));
+    }
+
+    #[test]
+    fn test_inverse_and_compose_of_tiny_determinant() {
+        // m ∘ m is valid with a determinant of about 10⁻¹², below DEFAULT_TOLERANCE
+        let m = MobiusTransform::new(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1e-6),
+        ).unwrap();
+        let mm = m.compose(&m);
+        let a = AntiMobiusTransform::from_mobius(mm);
+        // Adjugates and conjugates are exact, so inverting twice gives a back
+        assert_eq!(a.inverse().inverse().mobius_part().coefficients(), mm.coefficients());
+
+        // A well-conditioned map with tiny coefficients, accepted by new_checked
+        let (small, _) = MobiusTransform::new_checked(
+            Complex64::new(2e-7, 1e-7),
+            Complex64::new(1e-7, 0.0),
+            Complex64::new(0.0, -1e-7),
+            Complex64::new(1e-7, 0.0),
+        ).unwrap();
+        let a = AntiMobiusTransform::from_mobius(small);
+        let z = Complex64::new(0.3, -0.7);
+        assert!((a.inverse().apply(a.apply(z)) - z).norm() < 1e-10);
+        assert!((a.compose(&a).apply(z) - a.apply(a.apply(z))).norm() < 1e-10);
+        assert!(a.compose(&a.inverse()).approx_eq(&MobiusTransform::identity()));
+    }
+
+    #[test]
+    fn test_circle_inversion_small_radius() {
+        // The determinant -r² is far below DEFAULT_TOLERANCE
+        let inversion = AntiMobiusTransform::circle_inversion(Complex64::new(0.0, 0.0), 1e-7).unwrap();
+        let z = Complex64::from_polar(1e-7, 0.4);
+        assert!((inversion.apply(z) - z).norm() < 1e-20);
+        assert!((inversion.apply(z * 2.0) - z / 2.0).norm() < 1e-20);
+
+        // Off the origin r² - |p|² rounds, so the circle is only fixed to within
+        // about 10⁻¹⁶ |p|² / r
+        let center = Complex64::new(0.5, -0.25);
+        let inversion = AntiMobiusTransform::circle_inversion(center, 1e-6).unwrap();
+        let z = center + Complex64::from_polar(1e-6, 0.4);
+        assert!((inversion.apply(z) - z).norm() < 1e-9);
+
+        assert_eq!(
+            AntiMobiusTransform::circle_inversion(Complex64::new(1.0, 0.0), 1e-9).unwrap_err(),
+            TransformError::SingularTransform
+        );
+        assert_eq!(
+            AntiMobiusTransform::circle_inversion(Complex64::new(0.0, 0.0), 1e-200).unwrap_err(),
+            TransformError::SingularTransform
+        );
+    }
+}
@@ -0,0 +1,71 @@
+//! proptest support for fuzzing code that consumes Möbius transformations.
+//!
+//! Enabled with the `proptest` feature.
+
+use num_complex::Complex64;
+use proptest::prelude::*;
+use crate::transforms::MobiusTransform;
+
+/// Range of the real and imaginary parts of generated coefficients.
+const COEFFICIENT_RANGE: f64 = 10.0;
+
+/// Minimum accepted ratio |ad - bc| / max(|a|, |b|, |c|, |d|)².
+///
+/// Rejects transforms that would pass `new` but are numerically close to singular.
+const MIN_RELATIVE_DETERMINANT: f64 = 1e-3;
+
+fn complex_strategy() -> impl Strategy<Value = Complex64> {
+    (
+        -COEFFICIENT_RANGE..COEFFICIENT_RANGE,
+        -COEFFICIENT_RANGE..COEFFICIENT_RANGE,
+    )
+        .prop_map(|(re, im)| Complex64::new(re, im))
+}
+
+fn well_conditioned(a: Complex64, b: Complex64, c: Complex64, d: Complex64) -> Option<MobiusTransform> {
+    let scale = a.norm().max(b.norm()).max(c.norm()).max(d.norm());
+    let det = a * d - b * c;
+    if det.norm() < MIN_RELATIVE_DETERMINANT * scale * scale {
+        return None;
+    }
+    MobiusTransform::new(a, b, c, d).ok()
+}
+
+impl Arbitrary for MobiusTransform {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Generates well-conditioned transforms, occasionally mixing in the edge
+    /// cases of a pure translation z ↦ z + b and the inversion z ↦ 1/z.
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        let one = Complex64::new(1.0, 0.0);
+        let zero = Complex64::new(0.0, 0.0);
+
+        let general = (complex_strategy(), complex_strategy(), complex_strategy(), complex_strategy())
+            .prop_filter_map("near-singular transform", |(a, b, c, d)| well_conditioned(a, b, c, d));
+        let translation = complex_strategy()
+            .prop_map(move |b| MobiusTransform::new(one, b, zero, one).expect("Translation is always valid"));
+        let inversion = Just(MobiusTransform::new(zero, one, one, zero).expect("Inversion is always valid"));
+
+        prop_oneof![
+            8 => general,
+            1 => translation,
+            1 => inversion,
+        ]
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_inverse_round_trip(m in any::<MobiusTransform>(), re in -100.0..100.0f64, im in -100.0..100.0f64) {
+            let z = Complex64::new(re, im);
+            let result = m.apply(m.inverse().apply(z));
+            prop_assert!((result - z).norm() < 1e-6 * (1.0 + z.norm()));
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Builder for chaining elementary transformations.
+//!
+//! Provides the [`MobiusBuilder`] type, which composes translations, rotations,
+//! scalings and inversions in the order they are listed.
+
+use num_complex::Complex64;
+use crate::transforms::{MobiusTransform, TransformError};
+
+/// Accumulates a composition of elementary transformations.
+///
+/// Steps are applied in the order they are added: `builder().translate(t).rotate(θ)`
+/// first translates by t and then rotates, giving rotation ∘ translation. The first
+/// invalid step (for example a NaN translation) is remembered and returned by
+/// [`MobiusBuilder::build`]; later steps are then ignored.
+///
+/// # Examples
+/// ```
+/// use mobius_applicatio::MobiusTransform;
+/// use num_complex::Complex64;
+///
+/// // z ↦ 1 / (e^{iπ/2} (z + 1)) = -i / (z + 1)
+/// let m = MobiusTransform::builder()
+///     .translate(Complex64::new(1.0, 0.0))
+///     .rotate(std::f64::consts::FRAC_PI_2)
+///     .invert()
+///     .build()
+///     .unwrap();
+/// assert!((m.apply(Complex64::new(0.0, 0.0)) - Complex64::new(0.0, -1.0)).norm() < 1e-12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MobiusBuilder {
+    transform: Result<MobiusTransform, TransformError>,
+}
+
+impl MobiusTransform {
+    /// Starts a [`MobiusBuilder`] from the identity.
+    pub fn builder() -> MobiusBuilder {
+        MobiusBuilder::default()
+    }
+}
+
+impl Default for MobiusBuilder {
+    fn default() -> Self {
+        Self {
+            transform: Ok(MobiusTransform::identity()),
+        }
+    }
+}
+
+impl MobiusBuilder {
+    /// Follows the accumulated map by an arbitrary transformation.
+    pub fn then(self, next: &MobiusTransform) -> Self {
+        self.then_result(Ok(*next))
+    }
+
+    /// Follows the accumulated map by the translation z ↦ z + t.
+    pub fn translate(self, t: Complex64) -> Self {
+        self.then_result(MobiusTransform::translation(t))
+    }
+
+    /// Follows the accumulated map by the rotation z ↦ e^{iθ} z about the origin.
+    pub fn rotate(self, angle: f64) -> Self {
+        self.then_result(MobiusTransform::rotation(angle))
+    }
+
+    /// Follows the accumulated map by the scaling z ↦ kz about the origin.
+    ///
+    /// A complex factor scales by |k| and rotates by arg k; zero is invalid.
+    pub fn scale(self, factor: Complex64) -> Self {
+        self.then_result(MobiusTransform::from_affine(factor, Complex64::new(0.0, 0.0)))
+    }
+
+    /// Follows the accumulated map by the inversion z ↦ 1/z.
+    pub fn invert(self) -> Self {
+        let zero = Complex64::new(0.0, 0.0);
+        let one = Complex64::new(1.0, 0.0);
+        self.then_result(MobiusTransform::new(zero, one, one, zero))
+    }
+
+    /// Returns the composition of all steps.
+    ///
+    /// # Errors
+    /// Returns the error of the first invalid step.
+    pub fn build(self) -> Result<MobiusTransform, TransformError> {
+        self.transform
+    }
+
+    /// Appends a step, keeping the first error.
+    fn then_result(self, next: Result<MobiusTransform, TransformError>) -> Self {
+        Self {
+            transform: self.transform.and_then(|current| Ok(next?.compose(&current))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_composes_in_order() {
+        let t = Complex64::new(2.0, -1.0);
+        let angle = 0.7;
+        let built = MobiusTransform::builder().translate(t).rotate(angle).build().unwrap();
+
+        let translation = MobiusTransform::translation(t).unwrap();
+        let rotation = MobiusTransform::rotation(angle).unwrap();
+        assert!(built.approx_eq(&rotation.compose(&translation)));
+        assert!(!built.approx_eq(&translation.compose(&rotation)));
+
+        let z = Complex64::new(0.5, 0.5);
+        assert!((built.apply(z) - Complex64::from_polar(1.0, angle) * (z + t)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_builder_steps() {
+        let m = MobiusTransform::builder()
+            .scale(Complex64::new(2.0, 0.0))
+            .invert()
+            .then(&MobiusTransform::translation(Complex64::new(1.0, 0.0)).unwrap())
+            .build()
+            .unwrap();
+        // z ↦ 1/(2z) + 1
+        let z = Complex64::new(1.0, 1.0);
+        assert!((m.apply(z) - (1.0 / (2.0 * z) + 1.0)).norm() < 1e-12);
+
+        assert!(MobiusTransform::builder().build().unwrap().approx_eq(&MobiusTransform::identity()));
+    }
+
+    #[test]
+    fn test_builder_reports_first_error() {
+        let result = MobiusTransform::builder()
+            .rotate(1.0)
+            .scale(Complex64::new(0.0, 0.0))
+            .translate(Complex64::new(f64::NAN, 0.0))
+            .build();
+        assert_eq!(result.unwrap_err(), TransformError::SingularTransform);
+    }
+}
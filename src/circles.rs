@@ -0,0 +1,478 @@
+//! Generalized circles on the extended complex plane.
+//!
+//! Provides the [`GeneralizedCircle`] type. Möbius transformations map circles and
+//! lines (circles through infinity) to circles and lines, so both are represented
+//! by a single type.
+
+use num_complex::Complex64;
+use crate::complex_utils::is_infinity;
+use crate::transforms::{MobiusTransform, DEFAULT_TOLERANCE};
+
+/// A circle or a line in the extended complex plane.
+///
+/// Stored as the Hermitian form α|z|² + β z̄ + β̄ z + γ = 0 with α, γ real, i.e.
+/// the Hermitian matrix H = [[α, β], [β̄, γ]] evaluated on (z, 1). When α = 0 the
+/// form describes a line, which passes through the point at infinity; otherwise it
+/// describes the circle with center -β/α and radius √(|β|²/α² - γ/α).
+///
+/// The form is kept scaled so that its largest coefficient has magnitude 1. A form
+/// with |α| below [`DEFAULT_TOLERANCE`] after scaling is treated as a line; at that
+/// scale a circle is indistinguishable from a line anyway.
+#[derive(Debug, Clone, Copy)]
+pub struct GeneralizedCircle {
+    alpha: f64,
+    beta: Complex64,
+    gamma: f64,
+}
+
+impl GeneralizedCircle {
+    /// Creates the circle with the given center and radius.
+    ///
+    /// Returns `None` if the center is not finite or the radius is not a positive
+    /// finite number.
+    pub fn circle(center: Complex64, radius: f64) -> Option<Self> {
+        if !center.is_finite() || !radius.is_finite() || radius <= 0.0 {
+            return None;
+        }
+        Some(Self::from_form(1.0, -center, center.norm_sqr() - radius * radius))
+    }
+
+    /// Creates the line through `point` with the given direction.
+    ///
+    /// Returns `None` if `point` is not finite or `direction` is zero or not finite.
+    pub fn line(point: Complex64, direction: Complex64) -> Option<Self> {
+        if !point.is_finite() || !direction.is_finite() || direction.norm() == 0.0 {
+            return None;
+        }
+        // Re(n̄ (z - p)) = 0 with normal n = i · direction
+        let normal = Complex64::new(0.0, 1.0) * direction;
+        Some(Self::from_form(0.0, normal, -2.0 * (normal.conj() * point).re))
+    }
+
+    /// Creates the unique generalized circle through three distinct points.
+    ///
+    /// The result is a line when the points are collinear (within
+    /// [`DEFAULT_TOLERANCE`], relative to their spacing) or when one of them is the
+    /// point at infinity.
+    ///
+    /// Returns `None` if two of the points coincide, or any point is NaN.
+    pub fn through_points(z1: Complex64, z2: Complex64, z3: Complex64) -> Option<Self> {
+        if z1.is_nan() || z2.is_nan() || z3.is_nan() {
+            return None;
+        }
+
+        // A point at infinity makes the circle the line through the other two
+        match (is_infinity(z1), is_infinity(z2), is_infinity(z3)) {
+            (false, false, false) => {}
+            (true, false, false) => return Self::line_through(z2, z3),
+            (false, true, false) => return Self::line_through(z1, z3),
+            (false, false, true) => return Self::line_through(z1, z2),
+            _ => return None,
+        }
+
+        let w2 = z2 - z1;
+        let w3 = z3 - z1;
+        let w23 = z3 - z2;
+        let scale = w2.norm().max(w3.norm());
+        if w2.norm() <= DEFAULT_TOLERANCE * scale
+            || w3.norm() <= DEFAULT_TOLERANCE * scale
+            || w23.norm() <= DEFAULT_TOLERANCE * scale
+        {
+            return None;
+        }
+
+        // Twice the signed area of the triangle, relative to its side lengths
+        let cross = (w2.conj() * w3).im;
+        if cross.abs() <= DEFAULT_TOLERANCE * w2.norm() * w3.norm() {
+            return Self::line(z1, w2);
+        }
+
+        // Circumcenter of 0, w2, w3, shifted back by z1
+        let center = z1 + (w3 * w2.norm_sqr() - w2 * w3.norm_sqr()) / Complex64::new(0.0, 2.0 * cross);
+        Self::circle(center, (z1 - center).norm())
+    }
+
+    /// Creates the line through two distinct finite points.
+    fn line_through(z1: Complex64, z2: Complex64) -> Option<Self> {
+        let direction = z2 - z1;
+        if direction.norm() <= DEFAULT_TOLERANCE * z1.norm().max(z2.norm()) {
+            return None;
+        }
+        Self::line(z1, direction)
+    }
+
+    /// Creates a generalized circle from its Hermitian form, rescaled so the largest
+    /// coefficient has magnitude 1.
+    fn from_form(alpha: f64, beta: Complex64, gamma: f64) -> Self {
+        let scale = alpha.abs().max(beta.norm()).max(gamma.abs());
+        Self {
+            alpha: alpha / scale,
+            beta: beta / scale,
+            gamma: gamma / scale,
+        }
+    }
+
+    /// Returns true if this generalized circle is a line (passes through infinity).
+    pub fn is_line(&self) -> bool {
+        self.alpha.abs() < DEFAULT_TOLERANCE
+    }
+
+    /// Returns the center and radius, or `None` if this generalized circle is a line.
+    pub fn as_circle(&self) -> Option<(Complex64, f64)> {
+        if self.is_line() {
+            return None;
+        }
+        Some(self.center_and_radius())
+    }
+
+    /// Returns the line in Hessian normal form, or `None` if this generalized circle
+    /// is a circle.
+    ///
+    /// The result is (n, p) with unit normal n and offset p >= 0 such that the line is
+    /// {z : Re(n̄ z) = p}, i.e. the points whose projection onto n is p. So p is the
+    /// distance of the line from the origin, p·n is its point nearest the origin, and
+    /// n points from the origin towards the line; for a line through the origin
+    /// either normal may be returned.
+    pub fn as_line(&self) -> Option<(Complex64, f64)> {
+        if !self.is_line() {
+            return None;
+        }
+        // 2 Re(β̄ z) + γ = 0 is Re(β̄ z) / |β| = -γ / (2|β|)
+        let length = self.beta.norm();
+        let (normal, offset) = (self.beta / length, -self.gamma / (2.0 * length));
+        if offset < 0.0 {
+            Some((-normal, -offset))
+        } else {
+            Some((normal, offset))
+        }
+    }
+
+    /// Tests whether a point lies on the generalized circle, within Euclidean distance `tol`.
+    ///
+    /// The point at infinity lies on every line and on no circle.
+    pub fn contains(&self, z: Complex64, tol: f64) -> bool {
+        if is_infinity(z) {
+            return self.is_line();
+        }
+        self.distance_to(z) <= tol
+    }
+
+    /// Returns the point of the generalized circle nearest to `z`.
+    ///
+    /// For a line this is the orthogonal projection of z, and for a circle the point
+    /// where the ray from the center through z meets it. When z is the center of a
+    /// circle every point is equally near, and the rightmost point (center + radius)
+    /// is returned. The point at infinity is its own nearest point on a line; on a
+    /// circle the nearest point to it in the chordal metric is the one farthest from
+    /// the origin. NaN input gives NaN.
+    pub fn closest_point(&self, z: Complex64) -> Complex64 {
+        if z.is_nan() {
+            return Complex64::new(f64::NAN, f64::NAN);
+        }
+        if self.is_line() {
+            if is_infinity(z) {
+                return z;
+            }
+            // Move along the normal β until 2 Re(β̄ z) + γ = 0
+            let residual = 2.0 * (self.beta.conj() * z).re + self.gamma;
+            return z - self.beta * (residual / (2.0 * self.beta.norm_sqr()));
+        }
+
+        let (center, radius) = self.center_and_radius();
+        let direction = if is_infinity(z) { center } else { z - center };
+        if direction.norm() == 0.0 {
+            return center + radius;
+        }
+        center + direction * (radius / direction.norm())
+    }
+
+    /// Returns the Euclidean distance from a finite point to the generalized circle.
+    fn distance_to(&self, z: Complex64) -> f64 {
+        if self.is_line() {
+            // 2 Re(β̄ z) + γ = 0, with normal β
+            (2.0 * (self.beta.conj() * z).re + self.gamma).abs() / (2.0 * self.beta.norm())
+        } else {
+            let (center, radius) = self.center_and_radius();
+            ((z - center).norm() - radius).abs()
+        }
+    }
+
+    /// Returns the center and radius of a generalized circle that is not a line.
+    fn center_and_radius(&self) -> (Complex64, f64) {
+        let center = -self.beta / self.alpha;
+        let radius = (self.beta.norm_sqr() / (self.alpha * self.alpha) - self.gamma / self.alpha).sqrt();
+        (center, radius)
+    }
+}
+
+impl MobiusTransform {
+    /// Returns the image of a generalized circle under the transformation.
+    ///
+    /// Möbius transformations map generalized circles to generalized circles. The image
+    /// of the form H is H' = (M⁻¹)* H M⁻¹, where M is the matrix of the transformation.
+    /// The image is a line exactly when the circle passes through the pole -d/c.
+    pub fn apply_circle(&self, gc: &GeneralizedCircle) -> GeneralizedCircle {
+        let n = self.inverse().to_matrix();
+        let (p, q, r, s) = (n[[0, 0]], n[[0, 1]], n[[1, 0]], n[[1, 1]]);
+        let (alpha, beta, gamma) = (gc.alpha, gc.beta, gc.gamma);
+
+        let alpha_image = alpha * p.norm_sqr() + 2.0 * (p.conj() * beta * r).re + gamma * r.norm_sqr();
+        let beta_image = p.conj() * (q * alpha + beta * s) + r.conj() * (beta.conj() * q + s * gamma);
+        let gamma_image = alpha * q.norm_sqr() + 2.0 * (q.conj() * beta * s).re + gamma * s.norm_sqr();
+
+        GeneralizedCircle::from_form(alpha_image, beta_image, gamma_image)
+    }
+
+    /// Returns the images of several generalized circles under the transformation.
+    ///
+    /// A batch version of [`MobiusTransform::apply_circle`]. Möbius transformations
+    /// preserve tangency and angles between circles, so a circle packing maps to a
+    /// circle packing. Combined with [`groups::generate_group`](crate::groups::generate_group)
+    /// this gives the orbit of a packing under a group:
+    ///
+    /// ```
+    /// use mobius_applicatio::{GeneralizedCircle, MobiusTransform};
+    /// use mobius_applicatio::groups::generate_group;
+    /// use num_complex::Complex64;
+    ///
+    /// let generator = MobiusTransform::new(
+    ///     Complex64::new(2.0, 0.0),
+    ///     Complex64::new(1.0, 0.0),
+    ///     Complex64::new(1.0, 0.0),
+    ///     Complex64::new(2.0, 0.0),
+    /// ).unwrap();
+    /// let packing = [
+    ///     GeneralizedCircle::circle(Complex64::new(-1.0, 0.0), 1.0).unwrap(),
+    ///     GeneralizedCircle::circle(Complex64::new(1.0, 0.0), 1.0).unwrap(),
+    /// ];
+    ///
+    /// let orbit: Vec<GeneralizedCircle> = generate_group(&[generator], 3)
+    ///     .iter()
+    ///     .flat_map(|g| g.apply_circles(&packing))
+    ///     .collect();
+    /// assert_eq!(orbit.len(), 7 * packing.len());
+    /// ```
+    pub fn apply_circles(&self, circles: &[GeneralizedCircle]) -> Vec<GeneralizedCircle> {
+        circles.iter().map(|gc| self.apply_circle(gc)).collect()
+    }
+
+    /// Tests whether the image of a generalized circle is a line.
+    ///
+    /// The image passes through infinity exactly when the circle passes through the
+    /// pole -d/c, the point sent to infinity. For affine maps the pole is infinity
+    /// itself, so lines map to lines and circles to circles. `tol` is the Euclidean
+    /// distance within which the pole counts as lying on the circle (see
+    /// [`GeneralizedCircle::contains`]).
+    pub fn sends_to_line(&self, gc: &GeneralizedCircle, tol: f64) -> bool {
+        gc.contains(self.pole(), tol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex_utils::COMPLEX_INFINITY;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_through_points_on_unit_circle() {
+        let gc = GeneralizedCircle::through_points(
+            Complex64::from_polar(1.0, PI / 6.0),
+            Complex64::from_polar(1.0, 2.0 * PI / 3.0),
+            Complex64::from_polar(1.0, -PI / 2.0),
+        ).unwrap();
+
+        assert!(!gc.is_line());
+        assert!(gc.contains(Complex64::new(-1.0, 0.0), 1e-10));
+        assert!(gc.contains(Complex64::new(0.0, 1.0), 1e-10));
+        assert!(!gc.contains(Complex64::new(0.0, 0.0), 1e-10));
+        assert!(!gc.contains(COMPLEX_INFINITY, 1e-10));
+    }
+
+    #[test]
+    fn test_as_circle_and_as_line() {
+        let unit_circle = GeneralizedCircle::circle(Complex64::new(0.0, 0.0), 1.0).unwrap();
+        let (center, radius) = unit_circle.as_circle().unwrap();
+        assert!(center.norm() < 1e-12);
+        assert!((radius - 1.0).abs() < 1e-12);
+        assert!(unit_circle.as_line().is_none());
+
+        let real_axis = GeneralizedCircle::line(Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)).unwrap();
+        assert!(real_axis.as_circle().is_none());
+        let (normal, offset) = real_axis.as_line().unwrap();
+        assert!((normal.norm() - 1.0).abs() < 1e-12 && normal.re.abs() < 1e-12);
+        assert!(offset.abs() < 1e-12);
+
+        // Re z = 2, whichever way it is oriented
+        for direction in [Complex64::new(0.0, 1.0), Complex64::new(0.0, -3.0)] {
+            let line = GeneralizedCircle::line(Complex64::new(2.0, 5.0), direction).unwrap();
+            let (normal, offset) = line.as_line().unwrap();
+            assert!((normal - Complex64::new(1.0, 0.0)).norm() < 1e-12);
+            assert!((offset - 2.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_through_collinear_points() {
+        let gc = GeneralizedCircle::through_points(
+            Complex64::new(0.0, 1.0),
+            Complex64::new(1.0, 2.0),
+            Complex64::new(-3.0, -2.0),
+        ).unwrap();
+
+        assert!(gc.is_line());
+        assert!(gc.contains(Complex64::new(5.0, 6.0), 1e-10));
+        assert!(gc.contains(COMPLEX_INFINITY, 1e-10));
+        assert!(!gc.contains(Complex64::new(0.0, 0.0), 1e-10));
+    }
+
+    #[test]
+    fn test_through_points_with_infinity() {
+        let gc = GeneralizedCircle::through_points(
+            Complex64::new(1.0, 0.0),
+            COMPLEX_INFINITY,
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        assert!(gc.is_line());
+        assert!(gc.contains(Complex64::new(-7.0, 0.0), 1e-10));
+    }
+
+    #[test]
+    fn test_through_coincident_points() {
+        let z = Complex64::new(1.0, 1.0);
+        assert!(GeneralizedCircle::through_points(z, z, Complex64::new(0.0, 0.0)).is_none());
+        assert!(GeneralizedCircle::through_points(COMPLEX_INFINITY, z, COMPLEX_INFINITY).is_none());
+    }
+
+    #[test]
+    fn test_closest_point_on_circle() {
+        let unit = GeneralizedCircle::circle(Complex64::new(0.0, 0.0), 1.0).unwrap();
+        assert!((unit.closest_point(Complex64::new(2.0, 0.0)) - Complex64::new(1.0, 0.0)).norm() < 1e-12);
+        let inside = Complex64::new(0.0, -0.25);
+        assert!((unit.closest_point(inside) - Complex64::new(0.0, -1.0)).norm() < 1e-12);
+        assert!((unit.closest_point(Complex64::new(0.0, 0.0)) - Complex64::new(1.0, 0.0)).norm() < 1e-12);
+
+        let shifted = GeneralizedCircle::circle(Complex64::new(3.0, 4.0), 2.0).unwrap();
+        let nearest = shifted.closest_point(Complex64::new(0.0, 0.0));
+        assert!((nearest - Complex64::new(1.8, 2.4)).norm() < 1e-12);
+        assert!(shifted.contains(nearest, 1e-12));
+        assert!((shifted.closest_point(COMPLEX_INFINITY) - Complex64::new(4.2, 5.6)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_closest_point_on_line() {
+        // The line Re z = 1
+        let line = GeneralizedCircle::line(Complex64::new(1.0, 0.0), Complex64::new(0.0, 1.0)).unwrap();
+        assert!((line.closest_point(Complex64::new(-2.0, 3.0)) - Complex64::new(1.0, 3.0)).norm() < 1e-12);
+        assert!((line.closest_point(Complex64::new(1.0, -5.0)) - Complex64::new(1.0, -5.0)).norm() < 1e-12);
+        assert!(is_infinity(line.closest_point(COMPLEX_INFINITY)));
+
+        let diagonal = GeneralizedCircle::line(Complex64::new(0.0, 0.0), Complex64::new(1.0, 1.0)).unwrap();
+        assert!((diagonal.closest_point(Complex64::new(2.0, 0.0)) - Complex64::new(1.0, 1.0)).norm() < 1e-12);
+        assert!(diagonal.closest_point(Complex64::new(f64::NAN, 0.0)).is_nan());
+    }
+
+    #[test]
+    fn test_apply_circle() {
+        // f(z) = (z - 1) / (z + 1) maps the unit circle to the imaginary axis
+        let m = MobiusTransform::new(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(-1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        let unit_circle = GeneralizedCircle::circle(Complex64::new(0.0, 0.0), 1.0).unwrap();
+        let image = m.apply_circle(&unit_circle);
+        assert!(image.is_line());
+        assert!(image.contains(Complex64::new(0.0, 5.0), 1e-10));
+
+        // Three points define a circle; its image passes through their images
+        let (z1, z2, z3) = (Complex64::new(2.0, 0.0), Complex64::new(0.0, 3.0), Complex64::new(-1.0, -1.0));
+        let gc = GeneralizedCircle::through_points(z1, z2, z3).unwrap();
+        let image = m.apply_circle(&gc);
+        for z in [z1, z2, z3] {
+            assert!(image.contains(m.apply(z), 1e-10));
+        }
+        assert_eq!(image.is_line(), gc.contains(Complex64::new(-1.0, 0.0), 1e-10));
+    }
+
+    /// Returns the inversive product of two generalized circles, which is ±1 exactly
+    /// when they are tangent.
+    fn inversive_product(g1: &GeneralizedCircle, g2: &GeneralizedCircle) -> f64 {
+        let cross = g1.alpha * g2.gamma + g2.alpha * g1.gamma - 2.0 * (g1.beta * g2.beta.conj()).re;
+        let norm1 = (g1.beta.norm_sqr() - g1.alpha * g1.gamma).sqrt();
+        let norm2 = (g2.beta.norm_sqr() - g2.alpha * g2.gamma).sqrt();
+        cross / (2.0 * norm1 * norm2)
+    }
+
+    #[test]
+    fn test_apply_circles_preserves_tangency() {
+        // Two unit circles tangent at 0, and a circle of radius 1/2 tangent to both
+        let top = Complex64::new(0.0, 1.25_f64.sqrt());
+        let circles = [
+            GeneralizedCircle::circle(Complex64::new(-1.0, 0.0), 1.0).unwrap(),
+            GeneralizedCircle::circle(Complex64::new(1.0, 0.0), 1.0).unwrap(),
+            GeneralizedCircle::circle(top, 0.5).unwrap(),
+        ];
+        let tangency_points = [
+            (0, 1, Complex64::new(0.0, 0.0)),
+            (0, 2, Complex64::new(-1.0, 0.0) + (top + 1.0) / 1.5),
+            (1, 2, Complex64::new(1.0, 0.0) + (top - 1.0) / 1.5),
+        ];
+
+        let generic = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        // Pole at the tangency point 0: the first two circles become parallel lines
+        let through_pole = MobiusTransform::new(
+            Complex64::new(2.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap();
+
+        for m in [generic, through_pole] {
+            let images = m.apply_circles(&circles);
+            assert_eq!(images.len(), circles.len());
+            for (i, j, point) in tangency_points {
+                assert!((inversive_product(&images[i], &images[j]).abs() - 1.0).abs() < 1e-8);
+                let image = m.apply(point);
+                assert!(images[i].contains(image, 1e-8));
+                assert!(images[j].contains(image, 1e-8));
+            }
+        }
+
+        let images = through_pole.apply_circles(&circles);
+        assert!(images[0].is_line() && images[1].is_line() && !images[2].is_line());
+    }
+
+    #[test]
+    fn test_sends_to_line() {
+        // f(z) = (z - 1) / (z + 1) has its pole at -1
+        let m = MobiusTransform::new(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(-1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        let through_pole = GeneralizedCircle::circle(Complex64::new(-1.0, 1.0), 1.0).unwrap();
+        assert!(m.sends_to_line(&through_pole, 1e-10));
+        assert!(m.apply_circle(&through_pole).is_line());
+
+        let away_from_pole = GeneralizedCircle::circle(Complex64::new(1.0, 1.0), 0.5).unwrap();
+        assert!(!m.sends_to_line(&away_from_pole, 1e-10));
+        assert!(!m.apply_circle(&away_from_pole).is_line());
+
+        // A line not through the pole becomes a circle
+        let line = GeneralizedCircle::line(Complex64::new(0.0, 0.0), Complex64::new(0.0, 1.0)).unwrap();
+        assert!(!m.sends_to_line(&line, 1e-10));
+
+        // Affine maps send lines to lines and circles to circles
+        let affine = MobiusTransform::from_affine(Complex64::new(2.0, 1.0), Complex64::new(1.0, 0.0)).unwrap();
+        assert!(affine.sends_to_line(&line, 1e-10));
+        assert!(!affine.sends_to_line(&through_pole, 1e-10));
+    }
+}
@@ -0,0 +1,1040 @@
+//! Classification of Möbius transformations.
+//!
+//! Every non-identity Möbius transformation is conjugate to either a translation
+//! (parabolic) or a map z ↦ λz (elliptic, hyperbolic or loxodromic). The class is
+//! determined by the trace of the normalized matrix.
+
+use std::collections::HashMap;
+use num_complex::Complex64;
+use crate::complex_utils::{chordal_distance, format_complex, format_component, is_infinity, COMPLEX_INFINITY};
+use crate::sl2::Matrix2;
+use crate::transforms::{MobiusTransform, TransformError, DEFAULT_TOLERANCE};
+
+/// The conjugacy class type of a Möbius transformation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransformClass {
+    /// The identity map, which fixes every point.
+    Identity,
+    /// One fixed point; conjugate to a translation z ↦ z + 1. tr² = 4.
+    Parabolic,
+    /// Two fixed points; conjugate to a rotation z ↦ e^{iθ}z. tr² real in [0, 4).
+    Elliptic,
+    /// Two fixed points; conjugate to a real scaling z ↦ λz with λ > 0, λ ≠ 1. tr² real and > 4.
+    Hyperbolic,
+    /// Two fixed points; conjugate to z ↦ λz with |λ| ≠ 1 and λ not a positive real.
+    /// tr² not in [0, ∞).
+    Loxodromic,
+}
+
+impl TransformClass {
+    /// Returns the lower case name of the class, as used in descriptions.
+    fn word(self) -> &'static str {
+        match self {
+            TransformClass::Identity => "identity",
+            TransformClass::Parabolic => "parabolic",
+            TransformClass::Elliptic => "elliptic",
+            TransformClass::Hyperbolic => "hyperbolic",
+            TransformClass::Loxodromic => "loxodromic",
+        }
+    }
+}
+
+impl MobiusTransform {
+    /// Returns tr² / det = (a + d)² / (ad - bc).
+    ///
+    /// This is the squared trace of the normalized matrix. Unlike the trace itself it
+    /// does not depend on the scaling of the coefficients (or the sign ambiguity of
+    /// the normalization), and it is a complete conjugacy invariant of non-identity,
+    /// non-parabolic transformations.
+    pub fn trace_squared(&self) -> Complex64 {
+        let (a, _, _, d) = self.coefficients();
+        (a + d) * (a + d) / self.determinant()
+    }
+
+    /// Classifies the transformation, using [`DEFAULT_TOLERANCE`].
+    ///
+    /// See [`MobiusTransform::classify_with_tolerance`].
+    pub fn classify(&self) -> TransformClass {
+        self.classify_with_tolerance(DEFAULT_TOLERANCE)
+    }
+
+    /// Classifies the transformation by its squared trace σ = tr²/det.
+    ///
+    /// - Identity: the map is the identity (see [`MobiusTransform::is_identity`])
+    /// - Parabolic: |σ - 4| < `tol`
+    /// - Elliptic: |Im σ| < `tol` and 0 <= Re σ < 4
+    /// - Hyperbolic: |Im σ| < `tol` and Re σ > 4
+    /// - Loxodromic: otherwise
+    pub fn classify_with_tolerance(&self, tol: f64) -> TransformClass {
+        if self.is_identity(tol) {
+            return TransformClass::Identity;
+        }
+
+        let sigma = self.trace_squared();
+        if (sigma - 4.0).norm() < tol {
+            TransformClass::Parabolic
+        } else if sigma.im.abs() < tol && sigma.re > -tol && sigma.re < 4.0 {
+            TransformClass::Elliptic
+        } else if sigma.im.abs() < tol && sigma.re > 4.0 {
+            TransformClass::Hyperbolic
+        } else {
+            TransformClass::Loxodromic
+        }
+    }
+
+    /// Tests whether two transformations have the same type, comparing their
+    /// [`MobiusTransform::classify`] results.
+    ///
+    /// Conjugate maps always share a type, and so do a map and its inverse (they have
+    /// the same trace squared), so this is a cheap consistency check. The converse
+    /// does not hold: maps of the same type need not be conjugate.
+    pub fn same_class_as(&self, other: &MobiusTransform) -> bool {
+        self.classify() == other.classify()
+    }
+
+    /// Tests whether two transformations are conjugate, other = g ∘ self ∘ g⁻¹ for some g.
+    ///
+    /// In PSL(2,C) the conjugacy class is determined by the type and, for maps with two
+    /// fixed points, by the multiplier λ up to λ ↔ 1/λ (swapping the fixed points).
+    /// Since tr² / det = λ + 1/λ + 2, the multipliers are compared through
+    /// [`MobiusTransform::trace_squared`], within `tol`. All parabolic maps are
+    /// conjugate to z ↦ z + 1, and the identity only to itself. Both maps are
+    /// classified with `tol` (see [`MobiusTransform::classify_with_tolerance`]).
+    pub fn conjugate_equivalent(&self, other: &MobiusTransform, tol: f64) -> bool {
+        let class = self.classify_with_tolerance(tol);
+        if class != other.classify_with_tolerance(tol) {
+            return false;
+        }
+        match class {
+            TransformClass::Identity | TransformClass::Parabolic => true,
+            _ => (self.trace_squared() - other.trace_squared()).norm() < tol,
+        }
+    }
+
+    /// Tests whether the transformation is an involution, f ∘ f = identity with f not
+    /// the identity.
+    ///
+    /// By Cayley–Hamilton, M² = tr(M)·M - det(M)·I, so M² is a multiple of I exactly
+    /// when tr M = 0 (or M itself is a multiple of I). The check is therefore
+    /// |tr² / det| < `tol` (see [`MobiusTransform::trace_squared`]). Involutions are the
+    /// elliptic maps of angle π, such as z ↦ 1/z and z ↦ -z.
+    pub fn is_involution(&self, tol: f64) -> bool {
+        self.trace_squared().norm() < tol
+    }
+
+    /// Snaps a nearly special transformation to the exact special form.
+    ///
+    /// The candidates are tried in order: the identity, the translation z ↦ z + b/d,
+    /// the rotation about the origin z ↦ e^{iθ}z with θ = arg(a/d), and the involution
+    /// obtained by subtracting half the trace from the diagonal of the normalized
+    /// matrix (which makes the trace exactly zero). The first candidate within `tol`
+    /// of the transformation, in the sense of
+    /// [`MobiusTransform::approx_eq_with_tolerance`], is returned; if none is, the
+    /// transformation is returned unchanged. This is meant for cleaning up rounding
+    /// drift after long chains of operations, so `tol` should be small.
+    pub fn snap(&self, tol: f64) -> MobiusTransform {
+        let n = self.normalized_matrix();
+        let (a, b, c, d) = (n[0][0], n[0][1], n[1][0], n[1][1]);
+        let half_trace = (a + d) / 2.0;
+
+        // Candidates with an infinite coefficient (d = 0) are skipped
+        let candidates = [
+            Ok(MobiusTransform::identity()),
+            MobiusTransform::translation(b / d),
+            MobiusTransform::rotation((a / d).arg()),
+            MobiusTransform::new(a - half_trace, b, c, d - half_trace),
+        ];
+        candidates
+            .into_iter()
+            .flatten()
+            .find(|candidate| self.approx_eq_with_tolerance(candidate, tol))
+            .unwrap_or(*self)
+    }
+
+    /// Returns the fixed points of the transformation.
+    ///
+    /// - Identity: every point is fixed, and an empty vector is returned
+    /// - Parabolic: the single fixed point
+    /// - Otherwise: the two distinct fixed points, in no particular order
+    ///
+    /// The point at infinity is returned as [`COMPLEX_INFINITY`]; it is fixed exactly
+    /// when c = 0. Finite fixed points are the roots of cz² + (d - a)z - b = 0, computed
+    /// in a cancellation-free form. Zero checks use [`DEFAULT_TOLERANCE`] on the
+    /// normalized coefficients, consistently with [`MobiusTransform::classify`].
+    pub fn fixed_points(&self) -> Vec<Complex64> {
+        let class = self.classify();
+        if class == TransformClass::Identity {
+            return Vec::new();
+        }
+
+        let n = self.normalized_matrix();
+        let (a, b, c, d) = (n[0][0], n[0][1], n[1][0], n[1][1]);
+
+        if c.norm() < DEFAULT_TOLERANCE {
+            // Affine map z ↦ (az + b)/d fixes infinity
+            if class == TransformClass::Parabolic {
+                return vec![COMPLEX_INFINITY];
+            }
+            return vec![b / (d - a), COMPLEX_INFINITY];
+        }
+
+        if class == TransformClass::Parabolic {
+            return vec![(a - d) / (2.0 * c)];
+        }
+
+        // Roots of cz² + Bz + C with B = d - a, C = -b, via q = -(B ± √disc)/2
+        // with the sign chosen so that no cancellation occurs
+        let linear = d - a;
+        let sqrt_disc = (linear * linear + 4.0 * b * c).sqrt();
+        let sign = if (linear.conj() * sqrt_disc).re >= 0.0 { 1.0 } else { -1.0 };
+        let q = -(linear + sign * sqrt_disc) / 2.0;
+        vec![q / c, -b / q]
+    }
+
+    /// Returns the eigenvalues of the normalized matrix and the matching fixed points.
+    ///
+    /// With the matrix N normalized to det N = 1 (see [`MobiusTransform::normalize`]),
+    /// the eigenvalues are λ₁ = h + √(h² - 1) and λ₂ = 1/λ₁ with h = tr N / 2, the
+    /// square root signed so that |λ₁| >= 1 and evaluated without cancellation. So
+    /// λ₁λ₂ = 1 and λ₁ + λ₂ = tr N. Normalization fixes N only up to sign, and so the
+    /// eigenvalues are only determined up to a common sign.
+    ///
+    /// The fixed points are the eigenvectors written as ratios [p : 1]: the fixed
+    /// point p of the pair belongs to λ₁, with N (p, 1)ᵀ = λ₁ (p, 1)ᵀ, and q to λ₂. The
+    /// point at infinity is the eigenvector (1, 0). The multiplier at p is
+    /// f'(p) = 1/λ₁² and at q it is λ₁², so p is the attracting fixed point of a
+    /// hyperbolic or loxodromic map. The fixed points are `None` for the identity and
+    /// for parabolic maps, whose matrix has a repeated eigenvalue ±1 and is not
+    /// diagonalizable (or every point is fixed).
+    pub fn eigen(&self) -> (Complex64, Complex64, Option<(Complex64, Complex64)>) {
+        let n = self.normalized_matrix();
+        let lambda = leading_eigenvalue(&n);
+        let points = self.fixed_points();
+        let [p, q] = points[..] else {
+            return (lambda, lambda.inv(), None);
+        };
+        let fixed_points = if belongs_to_leading(&n, lambda, p, q) { (p, q) } else { (q, p) };
+        (lambda, lambda.inv(), Some(fixed_points))
+    }
+
+    /// Tests whether the transformation fixes `z`, i.e. f(z) ≈ z.
+    ///
+    /// The comparison uses the [`chordal_distance`] between z and f(z), which treats
+    /// infinity like any other point: f fixes ∞ exactly when the normalized c vanishes,
+    /// and a finite z is fixed when |f(z) - z| is small relative to 1 + |z|², which
+    /// also covers large finite points mapped to infinity. NaN is never fixed.
+    pub fn fixes(&self, z: Complex64, tol: f64) -> bool {
+        let image = if is_infinity(z) {
+            self.image_of_infinity()
+        } else {
+            self.apply(z)
+        };
+        chordal_distance(image, z) < tol
+    }
+
+    /// Returns the canonical form (g, λ) of a transformation with two fixed points.
+    ///
+    /// g is the transformation sending the first fixed point p returned by
+    /// [`MobiusTransform::fixed_points`] to 0 and the second, q, to infinity:
+    /// g(z) = (z - p) / (z - q), or z - p when q = ∞, or 1 / (z - q) when p = ∞.
+    /// Then g ∘ f ∘ g⁻¹ is z ↦ λz, so f = g⁻¹ ∘ (z ↦ λz) ∘ g, and λ = f'(p) is the
+    /// multiplier at p (the multiplier at q is 1/λ).
+    ///
+    /// Returns `None` for the identity and parabolic transformations, which have
+    /// fewer than two fixed points.
+    pub fn to_canonical_form(&self) -> Option<(MobiusTransform, Complex64)> {
+        let points = self.fixed_points();
+        let [p, q] = points[..] else {
+            return None;
+        };
+
+        let one = Complex64::new(1.0, 0.0);
+        let zero = Complex64::new(0.0, 0.0);
+        let g = if is_infinity(q) {
+            MobiusTransform::translation(-p).ok()?
+        } else if is_infinity(p) {
+            MobiusTransform::new(zero, one, one, -q).ok()?
+        } else {
+            MobiusTransform::new(one, -p, one, -q).ok()?
+        };
+
+        // The multiplier at p is 1/λ₁² if its eigenvector belongs to λ₁ (see
+        // `MobiusTransform::eigen`), and λ₁² otherwise
+        let n = self.normalized_matrix();
+        let lambda = leading_eigenvalue(&n);
+        let multiplier = if belongs_to_leading(&n, lambda, p, q) {
+            (lambda * lambda).inv()
+        } else {
+            lambda * lambda
+        };
+        Some((g, multiplier))
+    }
+
+    /// Returns a one-line summary of the action, for logging.
+    ///
+    /// The summary starts with the class in lower case (see
+    /// [`MobiusTransform::classify`]), followed by the fixed points and, for maps with
+    /// two fixed points, the multiplier λ at the first one in polar form |λ|∠arg λ with
+    /// the angle in degrees, as in `loxodromic, fixed points 1.2+0.3i and -0.5i,
+    /// multiplier 2∠30°`. The fixed points and multiplier are those of
+    /// [`MobiusTransform::to_canonical_form`], so the multiplier at the second fixed
+    /// point is 1/λ. The identity is described as `identity, every point fixed` and a
+    /// parabolic map as `parabolic, single fixed point p`. Numbers are rounded to four
+    /// decimals.
+    pub fn describe(&self) -> String {
+        let class = self.classify();
+        match class {
+            TransformClass::Identity => return "identity, every point fixed".to_string(),
+            TransformClass::Parabolic => {
+                let points: Vec<String> = self.fixed_points().into_iter().map(|z| format_complex(z, 4)).collect();
+                return format!("parabolic, single fixed point {}", points.join(" and "));
+            }
+            _ => {}
+        }
+        let word = class.word();
+        let points = self.fixed_points();
+        let (Some((_, lambda)), [p, q]) = (self.to_canonical_form(), &points[..]) else {
+            return word.to_string();
+        };
+        format!(
+            "{}, fixed points {} and {}, multiplier {}∠{}°",
+            word,
+            format_complex(*p, 4),
+            format_complex(*q, 4),
+            format_component(lambda.norm(), 4),
+            format_component(lambda.arg().to_degrees(), 4),
+        )
+    }
+
+    /// Creates the transformation fixing `p` and `q` with multiplier `lambda` at `p`.
+    ///
+    /// This inverts [`MobiusTransform::to_canonical_form`]: with g sending p to 0 and
+    /// q to infinity, the result is g⁻¹ ∘ (z ↦ λz) ∘ g. For finite p and q it is
+    /// f(z) = ((p - λq) z + pq(λ - 1)) / ((1 - λ) z + λp - q). Either fixed point may
+    /// be infinity; p = 0, q = ∞ gives z ↦ λz. The multiplier at q is 1/λ, and λ = 1
+    /// gives the identity.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InvalidArgument` if p and q coincide (within
+    /// [`DEFAULT_TOLERANCE`], relative to their magnitude) or either is NaN.
+    /// Returns `TransformError::SingularTransform` if λ is zero, and
+    /// `TransformError::InfiniteCoefficient` if it is infinite or NaN.
+    pub fn from_fixed_points_and_multiplier(
+        p: Complex64,
+        q: Complex64,
+        lambda: Complex64,
+    ) -> Result<MobiusTransform, TransformError> {
+        if p.is_nan() || q.is_nan() {
+            return Err(TransformError::InvalidArgument("fixed points must not be NaN".to_string()));
+        }
+        let coincide = match (is_infinity(p), is_infinity(q)) {
+            (true, true) => true,
+            (false, false) => (p - q).norm() <= DEFAULT_TOLERANCE * p.norm().max(q.norm()).max(1.0),
+            _ => false,
+        };
+        if coincide {
+            return Err(TransformError::InvalidArgument("fixed points must be distinct".to_string()));
+        }
+        if !lambda.is_finite() {
+            return Err(TransformError::InfiniteCoefficient);
+        }
+        if lambda.norm() == 0.0 {
+            return Err(TransformError::SingularTransform);
+        }
+
+        let one = Complex64::new(1.0, 0.0);
+        if is_infinity(q) {
+            // g(z) = z - p
+            return MobiusTransform::from_affine(lambda, p * (one - lambda));
+        }
+        if is_infinity(p) {
+            // g(z) = 1 / (z - q), so f(z) = q + (z - q) / λ
+            let inverse = lambda.inv();
+            return MobiusTransform::from_affine(inverse, q * (one - inverse));
+        }
+        MobiusTransform::new(p - lambda * q, p * q * (lambda - one), one - lambda, lambda * p - q)
+    }
+
+    /// Creates the transformation with fixed points `p` and `q` whose normalized
+    /// matrix (det = 1) has the given trace.
+    ///
+    /// The multiplier λ at p satisfies λ + 1/λ = tr² - 2, whose two roots λ and 1/λ
+    /// give the map and its inverse; the root with |λ| >= 1 is used, so p is the
+    /// repelling fixed point (or, for elliptic traces, the map rotates by arg λ about
+    /// p). The trace only matters up to sign, and tr = ±2 gives the identity. The
+    /// result is built by [`MobiusTransform::from_fixed_points_and_multiplier`].
+    ///
+    /// # Errors
+    /// Returns `TransformError::InfiniteCoefficient` if the trace is infinite or NaN,
+    /// and the errors of `from_fixed_points_and_multiplier` for invalid fixed points.
+    pub fn from_trace_and_fixed_points(
+        trace: Complex64,
+        p: Complex64,
+        q: Complex64,
+    ) -> Result<MobiusTransform, TransformError> {
+        if !trace.is_finite() {
+            return Err(TransformError::InfiniteCoefficient);
+        }
+        // Roots of λ² - sλ + 1 with s = tr² - 2 and discriminant s² - 4 = tr²(tr² - 4),
+        // taking the larger one so that no cancellation occurs
+        let sum = trace * trace - 2.0;
+        let root = trace * (trace * trace - 4.0).sqrt();
+        let sign = if (sum.conj() * root).re >= 0.0 { 1.0 } else { -1.0 };
+        let lambda = (sum + sign * root) / 2.0;
+        MobiusTransform::from_fixed_points_and_multiplier(p, q, lambda)
+    }
+
+    /// Creates the simplest representative of a conjugacy class.
+    ///
+    /// - Identity: the identity
+    /// - Parabolic: the translation z ↦ z + 1
+    /// - Elliptic, hyperbolic and loxodromic: the map z ↦ λz with fixed points 0 and ∞
+    ///   and multiplier λ = `param` at 0, e.g. `Complex64::from_polar(1.0, θ)` for the
+    ///   rotation by θ, or a real λ > 1 for a hyperbolic map
+    ///
+    /// `param` is ignored for the identity and parabolic classes, whose members are all
+    /// conjugate to the representative. Otherwise λ must give the requested class, as
+    /// decided by [`MobiusTransform::classify`]: |λ| = 1 and λ ≠ 1 for elliptic, λ a
+    /// positive real other than 1 for hyperbolic, and anything else for loxodromic.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InvalidArgument` if λ gives a map of another class.
+    /// Returns `TransformError::SingularTransform` if λ is zero, and
+    /// `TransformError::InfiniteCoefficient` if it is infinite or NaN.
+    pub fn class_representative(class: TransformClass, param: Complex64) -> Result<MobiusTransform, TransformError> {
+        let zero = Complex64::new(0.0, 0.0);
+        let representative = match class {
+            TransformClass::Identity => return Ok(MobiusTransform::identity()),
+            TransformClass::Parabolic => return MobiusTransform::translation(Complex64::new(1.0, 0.0)),
+            _ => MobiusTransform::from_affine(param, zero)?,
+        };
+        if representative.classify() != class {
+            return Err(TransformError::InvalidArgument(format!(
+                "multiplier {} does not give a{} {} map",
+                format_complex(param, 4),
+                if class == TransformClass::Elliptic { "n" } else { "" },
+                class.word(),
+            )));
+        }
+        Ok(representative)
+    }
+
+    /// Searches for a finite fixed point by Newton's method on g(z) = f(z) - z, starting
+    /// from `z0`.
+    ///
+    /// Iterates z ↦ z - g(z) / (f'(z) - 1) and returns the first iterate with
+    /// |f(z) - z| <= `tol`, or `None` if that does not happen within `max_iter`
+    /// steps, or an iterate becomes infinite, NaN or hits a critical point of g.
+    ///
+    /// This is meant for cross-checking [`MobiusTransform::fixed_points`] and for
+    /// selecting the fixed point attracting a given seed. Convergence is quadratic
+    /// near a simple fixed point but only linear for the double fixed point of a
+    /// parabolic map. A fixed point at infinity cannot be found; seeds then diverge and
+    /// `None` is returned.
+    pub fn fixed_points_newton(&self, z0: Complex64, max_iter: usize, tol: f64) -> Option<Complex64> {
+        let one = Complex64::new(1.0, 0.0);
+        let mut z = z0;
+        for _ in 0..=max_iter {
+            if !z.is_finite() {
+                return None;
+            }
+            let residual = self.apply(z) - z;
+            if residual.norm() <= tol {
+                return Some(z);
+            }
+            let slope = self.derivative(z) - one;
+            if !residual.is_finite() || !slope.is_finite() || slope.norm() == 0.0 {
+                return None;
+            }
+            z -= residual / slope;
+        }
+        None
+    }
+}
+
+/// Classifies each transformation, in order (see [`MobiusTransform::classify`]).
+pub fn classify_many(transforms: &[MobiusTransform]) -> Vec<TransformClass> {
+    transforms.iter().map(MobiusTransform::classify).collect()
+}
+
+/// Counts how many transformations fall into each class.
+///
+/// Classes with no members are absent from the map rather than counted as zero.
+pub fn class_histogram(transforms: &[MobiusTransform]) -> HashMap<TransformClass, usize> {
+    let mut histogram = HashMap::new();
+    for class in classify_many(transforms) {
+        *histogram.entry(class).or_insert(0) += 1;
+    }
+    histogram
+}
+
+/// Returns the eigenvalue λ₁ = h + √(h² - 1) of a matrix with determinant one, where
+/// h is half the trace and the root is signed so that |λ₁| >= 1 without cancellation.
+fn leading_eigenvalue(n: &Matrix2) -> Complex64 {
+    let half_trace = (n[0][0] + n[1][1]) / 2.0;
+    let root = (half_trace * half_trace - 1.0).sqrt();
+    if (half_trace.conj() * root).re >= 0.0 {
+        half_trace + root
+    } else {
+        half_trace - root
+    }
+}
+
+/// Returns true if the eigenvector of the fixed point p, rather than that of q, has
+/// the eigenvalue λ₁. The eigenvalue of (z, 1) is cz + d, and of (1, 0) it is a.
+fn belongs_to_leading(n: &Matrix2, lambda: Complex64, p: Complex64, q: Complex64) -> bool {
+    let eigenvalue_at = |z: Complex64| {
+        if is_infinity(z) {
+            n[0][0]
+        } else {
+            n[1][0] * z + n[1][1]
+        }
+    };
+    (eigenvalue_at(p) - lambda).norm() <= (eigenvalue_at(q) - lambda).norm()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contains_point(points: &[Complex64], z: Complex64) -> bool {
+        points.iter().any(|p| {
+            if is_infinity(z) {
+                is_infinity(*p)
+            } else {
+                (*p - z).norm() < 1e-10
+            }
+        })
+    }
+
+    #[test]
+    fn test_classify() {
+        let zero = Complex64::new(0.0, 0.0);
+        let scaling = |lambda: Complex64| MobiusTransform::from_affine(lambda, zero).unwrap();
+
+        assert_eq!(MobiusTransform::identity().classify(), TransformClass::Identity);
+        assert_eq!(
+            MobiusTransform::translation(Complex64::new(1.0, 2.0)).unwrap().classify(),
+            TransformClass::Parabolic
+        );
+        assert_eq!(scaling(Complex64::from_polar(1.0, 0.7)).classify(), TransformClass::Elliptic);
+        assert_eq!(scaling(Complex64::new(-1.0, 0.0)).classify(), TransformClass::Elliptic);
+        assert_eq!(scaling(Complex64::new(3.0, 0.0)).classify(), TransformClass::Hyperbolic);
+        assert_eq!(scaling(Complex64::new(-3.0, 0.0)).classify(), TransformClass::Loxodromic);
+        assert_eq!(scaling(Complex64::new(2.0, 1.0)).classify(), TransformClass::Loxodromic);
+    }
+
+    #[test]
+    fn test_from_trace_and_fixed_points() {
+        let p = Complex64::new(1.0, -1.0);
+        let q = Complex64::new(-2.0, 0.5);
+        for trace in [
+            Complex64::new(3.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(2.5, 1.5),
+            Complex64::new(-0.5, 2.0),
+        ] {
+            let m = MobiusTransform::from_trace_and_fixed_points(trace, p, q).unwrap();
+            assert!((m.trace_squared() - trace * trace).norm() < 1e-10, "trace {}", trace);
+            let points = m.fixed_points();
+            assert_eq!(points.len(), 2);
+            assert!(contains_point(&points, p) && contains_point(&points, q));
+            // p is repelling, or neutral for elliptic traces
+            assert!(m.derivative(p).norm() >= 1.0 - 1e-10);
+        }
+
+        let at_infinity = MobiusTransform::from_trace_and_fixed_points(Complex64::new(3.0, 0.0), p, COMPLEX_INFINITY).unwrap();
+        assert!(contains_point(&at_infinity.fixed_points(), COMPLEX_INFINITY));
+        assert_eq!(
+            MobiusTransform::from_trace_and_fixed_points(Complex64::new(f64::NAN, 0.0), p, q).unwrap_err(),
+            TransformError::InfiniteCoefficient
+        );
+        assert!(MobiusTransform::from_trace_and_fixed_points(Complex64::new(3.0, 0.0), p, p).is_err());
+    }
+
+    #[test]
+    fn test_inverse_preserves_class() {
+        let loxodromic = MobiusTransform::from_affine(Complex64::new(2.0, 1.0), Complex64::new(1.0, 0.0)).unwrap();
+        assert_eq!(loxodromic.inverse().classify(), TransformClass::Loxodromic);
+        assert!(loxodromic.same_class_as(&loxodromic.inverse()));
+
+        let parabolic = MobiusTransform::parabolic(Complex64::new(1.0, -1.0), Complex64::new(0.5, 2.0)).unwrap();
+        assert_eq!(parabolic.inverse().classify(), TransformClass::Parabolic);
+        assert!(parabolic.same_class_as(&parabolic.inverse()));
+
+        assert!(!loxodromic.same_class_as(&parabolic));
+    }
+
+    #[test]
+    fn test_conjugate_equivalent() {
+        let rotation = MobiusTransform::rotation(0.8).unwrap();
+        let g = MobiusTransform::translation(Complex64::new(2.0, -1.0)).unwrap();
+        let conjugate = g.compose(&rotation).compose(&g.inverse());
+        assert!(rotation.conjugate_equivalent(&conjugate, 1e-10));
+
+        // λ and 1/λ give conjugate maps (swap the fixed points with z ↦ 1/z)
+        let opposite = MobiusTransform::rotation(-0.8).unwrap();
+        assert!(rotation.conjugate_equivalent(&opposite, 1e-10));
+
+        let scaling = MobiusTransform::from_affine(Complex64::new(2.0, 0.0), Complex64::new(0.0, 0.0)).unwrap();
+        assert!(!rotation.conjugate_equivalent(&scaling, 1e-10));
+        assert!(!rotation.conjugate_equivalent(&MobiusTransform::rotation(0.9).unwrap(), 1e-10));
+
+        // All parabolic maps are conjugate
+        let translation = MobiusTransform::translation(Complex64::new(1.0, 0.0)).unwrap();
+        let parabolic = MobiusTransform::parabolic(Complex64::new(1.0, 1.0), Complex64::new(-3.0, 2.0)).unwrap();
+        assert!(translation.conjugate_equivalent(&parabolic, 1e-10));
+        assert!(!translation.conjugate_equivalent(&MobiusTransform::identity(), 1e-10));
+    }
+
+    #[test]
+    fn test_snap() {
+        let zero = Complex64::new(0.0, 0.0);
+        let near_identity = MobiusTransform::new(
+            Complex64::new(1.0 + 1e-12, 0.0),
+            Complex64::new(0.0, 1e-12),
+            Complex64::new(-1e-12, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        assert_eq!(near_identity.snap(1e-9).to_array(), MobiusTransform::identity().to_array());
+
+        // A rotation by 0.7 with drift in every coefficient
+        let near_rotation = MobiusTransform::new(
+            Complex64::from_polar(1.0, 0.35) + 1e-12,
+            Complex64::new(1e-12, -1e-12),
+            Complex64::new(0.0, 1e-12),
+            Complex64::from_polar(1.0, -0.35),
+        ).unwrap();
+        let snapped = near_rotation.snap(1e-9);
+        let (_, b, c, _) = snapped.coefficients();
+        assert_eq!((b, c), (zero, zero));
+        assert!(snapped.approx_eq(&MobiusTransform::rotation(0.7).unwrap()));
+
+        let near_translation = MobiusTransform::new(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(2.0, -1.0),
+            Complex64::new(1e-13, 0.0),
+            Complex64::new(1.0, 1e-12),
+        ).unwrap();
+        let (a, b, c, d) = near_translation.snap(1e-9).coefficients();
+        assert_eq!((a, c, d), (Complex64::new(1.0, 0.0), zero, Complex64::new(1.0, 0.0)));
+        assert!((b - Complex64::new(2.0, -1.0)).norm() < 1e-9);
+
+        // z ↦ (z + 2) / (3z - 1 + ε) is nearly an involution
+        let one = Complex64::new(1.0, 0.0);
+        let near_involution = MobiusTransform::new(one, Complex64::new(2.0, 0.0), Complex64::new(3.0, 0.0), Complex64::new(-1.0 + 1e-11, 0.0)).unwrap();
+        assert!(!near_involution.is_involution(1e-25));
+        assert!(near_involution.snap(1e-9).trace_squared().norm() < 1e-25);
+
+        // Far from every special form, nothing changes
+        let generic = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            one,
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        assert_eq!(generic.snap(1e-6).to_array(), generic.to_array());
+    }
+
+    #[test]
+    fn test_is_involution() {
+        let zero = Complex64::new(0.0, 0.0);
+        let one = Complex64::new(1.0, 0.0);
+        let reciprocal = MobiusTransform::new(zero, one, one, zero).unwrap();
+        let negation = MobiusTransform::from_affine(-one, zero).unwrap();
+        // z ↦ (z + 2) / (3z - 1) has trace 0
+        let swap = MobiusTransform::new(one, Complex64::new(2.0, 0.0), Complex64::new(3.0, 0.0), -one).unwrap();
+
+        for m in [reciprocal, negation, swap] {
+            assert!(m.is_involution(1e-10));
+            assert!(m.compose(&m).approx_eq(&MobiusTransform::identity()));
+        }
+
+        let generic = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        assert!(!generic.is_involution(1e-10));
+        assert!(!MobiusTransform::identity().is_involution(1e-10));
+        assert!(!MobiusTransform::rotation(std::f64::consts::FRAC_PI_2).unwrap().is_involution(1e-10));
+    }
+
+    #[test]
+    fn test_fixed_points() {
+        // f(z) = (2z + 1) / (z + 1) fixes the roots of z² - z - 1
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        let points = m.fixed_points();
+        assert_eq!(points.len(), 2);
+        let sqrt5 = 5.0_f64.sqrt();
+        assert!(contains_point(&points, Complex64::new((1.0 + sqrt5) / 2.0, 0.0)));
+        assert!(contains_point(&points, Complex64::new((1.0 - sqrt5) / 2.0, 0.0)));
+
+        // f(z) = 2z + 1 fixes -1 and ∞
+        let m = MobiusTransform::from_affine(Complex64::new(2.0, 0.0), Complex64::new(1.0, 0.0)).unwrap();
+        let points = m.fixed_points();
+        assert_eq!(points.len(), 2);
+        assert!(contains_point(&points, Complex64::new(-1.0, 0.0)));
+        assert!(contains_point(&points, COMPLEX_INFINITY));
+
+        assert!(MobiusTransform::identity().fixed_points().is_empty());
+    }
+
+    #[test]
+    fn test_eigen() {
+        let loxodromic = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let elliptic = MobiusTransform::elliptic(Complex64::new(0.5, -1.0), 1.3).unwrap();
+        let affine = MobiusTransform::from_affine(Complex64::new(2.0, 0.0), Complex64::new(1.0, 1.0)).unwrap();
+
+        for m in [loxodromic, elliptic, affine] {
+            let (lambda1, lambda2, fixed_points) = m.eigen();
+            let (a, _, _, d) = m.normalize().coefficients();
+            assert!((lambda1 * lambda2 - 1.0).norm() < 1e-12);
+            assert!((lambda1 + lambda2 - (a + d)).norm() < 1e-12);
+            assert!(lambda1.norm() >= 1.0);
+
+            // The multipliers at the fixed points are 1/λ₁² and λ₁²
+            let (p, q) = fixed_points.unwrap();
+            assert!(m.fixes(p, 1e-10) && m.fixes(q, 1e-10));
+            if !is_infinity(p) {
+                assert!((m.derivative(p) - 1.0 / (lambda1 * lambda1)).norm() < 1e-9);
+            }
+            if !is_infinity(q) {
+                assert!((m.derivative(q) - lambda1 * lambda1).norm() < 1e-9);
+            }
+        }
+
+        // z ↦ 2z + 1 + i attracts towards ∞ and repels from -1 - i
+        let (_, _, fixed_points) = affine.eigen();
+        let (p, q) = fixed_points.unwrap();
+        assert!(is_infinity(p));
+        assert!((q - Complex64::new(-1.0, -1.0)).norm() < 1e-12);
+
+        let parabolic = MobiusTransform::parabolic(Complex64::new(1.0, 1.0), Complex64::new(0.5, 0.0)).unwrap();
+        for m in [parabolic, MobiusTransform::identity()] {
+            let (lambda1, lambda2, fixed_points) = m.eigen();
+            assert!((lambda1 - lambda2).norm() < 1e-6);
+            assert!((lambda1 * lambda1 - 1.0).norm() < 1e-6);
+            assert!(fixed_points.is_none());
+        }
+    }
+
+    #[test]
+    fn test_fixes() {
+        let rotation = MobiusTransform::rotation(0.8).unwrap();
+        let zero = Complex64::new(0.0, 0.0);
+        let one = Complex64::new(1.0, 0.0);
+        assert!(rotation.fixes(zero, 1e-12));
+        assert!(rotation.fixes(COMPLEX_INFINITY, 1e-12));
+        assert!(!rotation.fixes(one, 1e-6));
+        assert!(!rotation.fixes(Complex64::new(f64::NAN, 0.0), 1.0));
+
+        // z ↦ 1/z fixes ±1 and swaps 0 and ∞
+        let inversion = MobiusTransform::new(zero, one, one, zero).unwrap();
+        assert!(inversion.fixes(one, 1e-12));
+        assert!(inversion.fixes(-one, 1e-12));
+        assert!(!inversion.fixes(zero, 1e-6));
+        assert!(!inversion.fixes(COMPLEX_INFINITY, 1e-6));
+
+        // Every fixed point reported by fixed_points is fixed
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        for p in m.fixed_points() {
+            assert!(m.fixes(p, 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_canonical_form_reconstructs() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let (g, lambda) = m.to_canonical_form().unwrap();
+        let scaling = MobiusTransform::from_affine(lambda, Complex64::new(0.0, 0.0)).unwrap();
+        assert!(g.inverse().compose(&scaling).compose(&g).approx_eq(&m));
+
+        // λ is the derivative at the fixed point sent to 0
+        let p = g.inverse().apply(Complex64::new(0.0, 0.0));
+        assert!((m.derivative(p) - lambda).norm() < 1e-10);
+    }
+
+    #[test]
+    fn test_canonical_form_with_infinity() {
+        // f(z) = 2z + 1 fixes -1 and ∞ with multiplier 2 at -1
+        let m = MobiusTransform::from_affine(Complex64::new(2.0, 0.0), Complex64::new(1.0, 0.0)).unwrap();
+        let (g, lambda) = m.to_canonical_form().unwrap();
+        assert!((lambda - Complex64::new(2.0, 0.0)).norm() < 1e-10);
+        let scaling = MobiusTransform::from_affine(lambda, Complex64::new(0.0, 0.0)).unwrap();
+        assert!(g.compose(&m).compose(&g.inverse()).approx_eq(&scaling));
+
+        assert!(MobiusTransform::identity().to_canonical_form().is_none());
+        assert!(MobiusTransform::parabolic(Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0))
+            .unwrap()
+            .to_canonical_form()
+            .is_none());
+    }
+
+    #[test]
+    fn test_from_fixed_points_and_multiplier() {
+        let (p, q) = (Complex64::new(1.0, 2.0), Complex64::new(-0.5, 0.0));
+        let lambda = Complex64::new(0.5, 1.5);
+        let m = MobiusTransform::from_fixed_points_and_multiplier(p, q, lambda).unwrap();
+        assert!((m.apply(p) - p).norm() < 1e-10);
+        assert!((m.apply(q) - q).norm() < 1e-10);
+        assert!((m.derivative(p) - lambda).norm() < 1e-10);
+        assert!((m.derivative(q) - lambda.inv()).norm() < 1e-10);
+
+        // Round trip through the canonical form
+        let (g, multiplier) = m.to_canonical_form().unwrap();
+        let p_canonical = g.inverse().apply(Complex64::new(0.0, 0.0));
+        let rebuilt = MobiusTransform::from_fixed_points_and_multiplier(
+            p_canonical,
+            g.inverse().apply(COMPLEX_INFINITY),
+            multiplier,
+        ).unwrap();
+        assert!(rebuilt.approx_eq(&m));
+    }
+
+    #[test]
+    fn test_from_fixed_points_and_multiplier_at_infinity() {
+        let zero = Complex64::new(0.0, 0.0);
+        let lambda = Complex64::new(2.0, 1.0);
+        let m = MobiusTransform::from_fixed_points_and_multiplier(zero, COMPLEX_INFINITY, lambda).unwrap();
+        assert!(m.approx_eq(&MobiusTransform::from_affine(lambda, zero).unwrap()));
+
+        // With p = ∞ the multiplier λ applies at infinity, and 1/λ at q
+        let q = Complex64::new(1.0, -1.0);
+        let m = MobiusTransform::from_fixed_points_and_multiplier(COMPLEX_INFINITY, q, lambda).unwrap();
+        assert!((m.apply(q) - q).norm() < 1e-10);
+        assert!(is_infinity(m.apply(COMPLEX_INFINITY)));
+        assert!((m.derivative(q) - lambda.inv()).norm() < 1e-10);
+
+        let one = Complex64::new(1.0, 0.0);
+        assert!(matches!(
+            MobiusTransform::from_fixed_points_and_multiplier(one, one, lambda),
+            Err(TransformError::InvalidArgument(_))
+        ));
+        assert!(MobiusTransform::from_fixed_points_and_multiplier(COMPLEX_INFINITY, COMPLEX_INFINITY, lambda).is_err());
+        assert!(MobiusTransform::from_fixed_points_and_multiplier(zero, one, zero).is_err());
+    }
+
+    #[test]
+    fn test_fixed_points_newton() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        assert_eq!(m.classify(), TransformClass::Loxodromic);
+        let analytic = m.fixed_points();
+        assert_eq!(analytic.len(), 2);
+
+        // Seeds near each analytic fixed point converge to it
+        let offset = Complex64::new(0.1, -0.1);
+        for p in &analytic {
+            let found = m.fixed_points_newton(p + offset, 50, 1e-12).unwrap();
+            assert!((found - p).norm() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_fixed_points_newton_failure() {
+        // A translation fixes only infinity
+        let m = MobiusTransform::translation(Complex64::new(1.0, 0.0)).unwrap();
+        assert!(m.fixed_points_newton(Complex64::new(0.0, 0.0), 50, 1e-12).is_none());
+    }
+
+    #[test]
+    fn test_parabolic_constructor() {
+        let p = Complex64::new(1.0, -2.0);
+        let m = MobiusTransform::parabolic(p, Complex64::new(0.5, 0.5)).unwrap();
+        assert_eq!(m.classify(), TransformClass::Parabolic);
+
+        let points = m.fixed_points();
+        assert_eq!(points.len(), 1);
+        assert!((points[0] - p).norm() < 1e-10);
+        assert!((m.apply(p) - p).norm() < 1e-10);
+    }
+
+    #[test]
+    fn test_parabolic_at_infinity_is_translation() {
+        let t = Complex64::new(2.0, -1.0);
+        let m = MobiusTransform::parabolic(COMPLEX_INFINITY, t).unwrap();
+        assert_eq!(m.classify(), TransformClass::Parabolic);
+        assert!(m.approx_eq(&MobiusTransform::translation(t).unwrap()));
+
+        let points = m.fixed_points();
+        assert_eq!(points.len(), 1);
+        assert!(is_infinity(points[0]));
+    }
+
+    #[test]
+    fn test_elliptic_constructor() {
+        let p = Complex64::new(0.5, 1.5);
+        let angle = 0.8;
+        let m = MobiusTransform::elliptic(p, angle).unwrap();
+        assert_eq!(m.classify(), TransformClass::Elliptic);
+        assert!((m.apply(p) - p).norm() < 1e-10);
+
+        // The antipode -1/p̄ is the second fixed point
+        let points = m.fixed_points();
+        assert_eq!(points.len(), 2);
+        assert!(contains_point(&points, p));
+        assert!(contains_point(&points, -p.conj().inv()));
+
+        // Near p the map is a rotation by the angle
+        let epsilon = Complex64::new(1e-6, 0.0);
+        let image = m.apply(p + epsilon) - p;
+        assert!((image.arg() - angle).abs() < 1e-5);
+        assert!((image.norm() - epsilon.norm()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_elliptic_at_origin_is_rotation() {
+        let m = MobiusTransform::elliptic(Complex64::new(0.0, 0.0), 1.2).unwrap();
+        assert!(m.approx_eq(&MobiusTransform::rotation(1.2).unwrap()));
+
+        let m = MobiusTransform::elliptic(COMPLEX_INFINITY, 1.2).unwrap();
+        assert!(m.approx_eq(&MobiusTransform::rotation(-1.2).unwrap()));
+        assert_eq!(m.classify(), TransformClass::Elliptic);
+
+        let full_turn = MobiusTransform::elliptic(Complex64::new(1.0, 1.0), 2.0 * std::f64::consts::PI).unwrap();
+        assert_eq!(full_turn.classify(), TransformClass::Identity);
+    }
+
+    #[test]
+    fn test_invalid_fixed_point_constructors() {
+        let result = MobiusTransform::parabolic(Complex64::new(f64::NAN, 0.0), Complex64::new(1.0, 0.0));
+        assert!(result.is_err());
+        assert!(MobiusTransform::elliptic(Complex64::new(1.0, 0.0), f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_classify_many_and_histogram() {
+        let zero = Complex64::new(0.0, 0.0);
+        let scaling = |lambda: Complex64| MobiusTransform::from_affine(lambda, zero).unwrap();
+        let transforms = [
+            MobiusTransform::identity(),
+            MobiusTransform::translation(Complex64::new(1.0, 2.0)).unwrap(),
+            scaling(Complex64::from_polar(1.0, 0.7)),
+            scaling(Complex64::new(3.0, 0.0)),
+            scaling(Complex64::new(2.0, 1.0)),
+            scaling(Complex64::new(0.5, 0.0)),
+        ];
+
+        assert_eq!(
+            classify_many(&transforms),
+            vec![
+                TransformClass::Identity,
+                TransformClass::Parabolic,
+                TransformClass::Elliptic,
+                TransformClass::Hyperbolic,
+                TransformClass::Loxodromic,
+                TransformClass::Hyperbolic,
+            ]
+        );
+
+        let histogram = class_histogram(&transforms);
+        assert_eq!(histogram.len(), 5);
+        assert_eq!(histogram[&TransformClass::Hyperbolic], 2);
+        for class in [TransformClass::Identity, TransformClass::Parabolic, TransformClass::Elliptic, TransformClass::Loxodromic] {
+            assert_eq!(histogram[&class], 1);
+        }
+        assert!(class_histogram(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_describe() {
+        let zero = Complex64::new(0.0, 0.0);
+        let scaling = |lambda: Complex64| MobiusTransform::from_affine(lambda, zero).unwrap();
+        assert_eq!(MobiusTransform::identity().describe(), "identity, every point fixed");
+        assert_eq!(
+            MobiusTransform::translation(Complex64::new(1.0, 2.0)).unwrap().describe(),
+            "parabolic, single fixed point ∞"
+        );
+
+        let rotation = scaling(Complex64::from_polar(1.0, std::f64::consts::FRAC_PI_2));
+        assert_eq!(rotation.describe(), "elliptic, fixed points 0 and ∞, multiplier 1∠90°");
+        assert!(scaling(Complex64::new(3.0, 0.0)).describe().starts_with("hyperbolic, fixed points "));
+
+        let loxodromic = scaling(Complex64::from_polar(2.0, 30f64.to_radians()));
+        assert_eq!(loxodromic.describe(), "loxodromic, fixed points 0 and ∞, multiplier 2∠30°");
+
+        // The multiplier belongs to the first fixed point listed
+        let m = MobiusTransform::from_fixed_points_and_multiplier(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, -0.5),
+            Complex64::from_polar(3.0, -0.5),
+        ).unwrap();
+        let description = m.describe();
+        assert!(description.starts_with("loxodromic, fixed points "), "{}", description);
+        let (_, lambda) = m.to_canonical_form().unwrap();
+        assert!(description.ends_with(&format!("multiplier {}∠{}°",
+            format_component(lambda.norm(), 4),
+            format_component(lambda.arg().to_degrees(), 4),
+        )));
+    }
+
+    #[test]
+    fn test_class_representative() {
+        let cases = [
+            (TransformClass::Identity, Complex64::new(7.0, 1.0)),
+            (TransformClass::Parabolic, Complex64::new(0.0, 0.0)),
+            (TransformClass::Elliptic, Complex64::from_polar(1.0, 0.8)),
+            (TransformClass::Hyperbolic, Complex64::new(2.5, 0.0)),
+            (TransformClass::Hyperbolic, Complex64::new(0.25, 0.0)),
+            (TransformClass::Loxodromic, Complex64::new(1.0, 2.0)),
+            (TransformClass::Loxodromic, Complex64::new(-3.0, 0.0)),
+        ];
+        for (class, param) in cases {
+            let m = MobiusTransform::class_representative(class, param).unwrap();
+            assert_eq!(m.classify(), class, "{:?} with {}", class, param);
+        }
+
+        let z = Complex64::new(0.5, -2.0);
+        let parabolic = MobiusTransform::class_representative(TransformClass::Parabolic, z).unwrap();
+        assert!((parabolic.apply(z) - (z + 1.0)).norm() < 1e-12);
+        let lambda = Complex64::new(1.0, 2.0);
+        let loxodromic = MobiusTransform::class_representative(TransformClass::Loxodromic, lambda).unwrap();
+        assert!((loxodromic.apply(z) - lambda * z).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_class_representative_rejects_other_classes() {
+        let mismatches = [
+            (TransformClass::Elliptic, Complex64::new(2.0, 0.0)),
+            (TransformClass::Elliptic, Complex64::new(1.0, 0.0)),
+            (TransformClass::Hyperbolic, Complex64::new(1.0, 1.0)),
+            (TransformClass::Loxodromic, Complex64::from_polar(1.0, 0.3)),
+        ];
+        for (class, param) in mismatches {
+            assert!(matches!(
+                MobiusTransform::class_representative(class, param),
+                Err(TransformError::InvalidArgument(_))
+            ));
+        }
+        assert_eq!(
+            MobiusTransform::class_representative(TransformClass::Hyperbolic, Complex64::new(0.0, 0.0)).unwrap_err(),
+            TransformError::SingularTransform
+        );
+    }
+}
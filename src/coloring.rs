@@ -0,0 +1,109 @@
+//! Domain coloring of complex values.
+//!
+//! Maps complex values to RGB colors independently of any GUI toolkit, so the same
+//! coloring can be used in interactive and headless renders.
+
+use num_complex::Complex64;
+use std::f64::consts::{FRAC_2_PI, PI};
+use crate::complex_utils::is_infinity;
+
+/// Maps a complex value to an RGB color by domain coloring.
+///
+/// The hue is the argument of `z`: red on the positive real axis, then yellow-green
+/// on the positive imaginary axis, cyan on the negative real axis and violet on the
+/// negative imaginary axis. Saturation is full, and the value is
+/// (2/π) · atan|z|, which compresses [0, ∞) smoothly onto [0, 1) and swaps dark and
+/// bright under z ↦ 1/z. The unit circle has value 1/2.
+///
+/// Zero maps to black and the point at infinity to white. NaN, which is not a point
+/// of the extended complex plane, also maps to black.
+///
+/// # Examples
+/// ```
+/// use mobius_applicatio::coloring::domain_color;
+/// use mobius_applicatio::complex_utils::COMPLEX_INFINITY;
+/// use num_complex::Complex64;
+///
+/// assert_eq!(domain_color(Complex64::new(0.0, 0.0)), [0, 0, 0]);
+/// assert_eq!(domain_color(COMPLEX_INFINITY), [255, 255, 255]);
+/// ```
+pub fn domain_color(z: Complex64) -> [u8; 3] {
+    if is_infinity(z) {
+        return [255, 255, 255];
+    }
+    if z.is_nan() {
+        return [0, 0, 0];
+    }
+
+    // Hue in [0, 1), with the negative real axis at 1/2
+    let hue = (z.arg() / (2.0 * PI)).rem_euclid(1.0);
+    let value = FRAC_2_PI * z.norm().atan();
+    hsv_to_rgb(hue, 1.0, value)
+}
+
+/// Converts an HSV color with all components in [0, 1] to RGB.
+fn hsv_to_rgb(hue: f64, saturation: f64, value: f64) -> [u8; 3] {
+    let sector = hue * 6.0;
+    let chroma = value * saturation;
+    let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match sector as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = value - chroma;
+    let to_byte = |channel: f64| ((channel + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+    [to_byte(r), to_byte(g), to_byte(b)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hue_on_axes() {
+        let r = 1e6;
+        // Hue 0: pure red
+        let [red, green, blue] = domain_color(Complex64::new(r, 0.0));
+        assert_eq!((red, green, blue), (255, 0, 0));
+
+        // Hue 1/4 (90°): full green, half red, no blue
+        let [red, green, blue] = domain_color(Complex64::new(0.0, r));
+        assert_eq!((green, blue), (255, 0));
+        assert!(red.abs_diff(128) <= 1);
+
+        // Hue 1/2: cyan
+        let [red, green, blue] = domain_color(Complex64::new(-r, 0.0));
+        assert_eq!((red, green, blue), (0, 255, 255));
+
+        // Hue 3/4 (270°): full blue, half red, no green
+        let [red, green, blue] = domain_color(Complex64::new(0.0, -r));
+        assert_eq!((green, blue), (0, 255));
+        assert!(red.abs_diff(128) <= 1);
+    }
+
+    #[test]
+    fn test_value_from_modulus() {
+        // The unit circle has value 1/2
+        assert_eq!(domain_color(Complex64::new(1.0, 0.0)), [128, 0, 0]);
+
+        // Brightness increases with |z| along a ray
+        let brightness = |r: f64| domain_color(Complex64::new(r, 0.0))[0];
+        assert!(brightness(0.1) < brightness(1.0));
+        assert!(brightness(1.0) < brightness(10.0));
+
+        // z and 1/z have complementary value
+        let (dark, bright) = (brightness(0.25), brightness(4.0));
+        assert_eq!(u16::from(dark) + u16::from(bright), 255);
+    }
+
+    #[test]
+    fn test_special_points() {
+        assert_eq!(domain_color(Complex64::new(0.0, 0.0)), [0, 0, 0]);
+        assert_eq!(domain_color(Complex64::new(f64::NEG_INFINITY, 1.0)), [255, 255, 255]);
+        assert_eq!(domain_color(Complex64::new(f64::NAN, 0.0)), [0, 0, 0]);
+    }
+}
@@ -3,6 +3,7 @@
 //! The extended complex plane includes all points in ℂ plus a single point at infinity.
 
 use num_complex::Complex64;
+use ndarray::Array1;
 
 /// The point at infinity on the extended complex plane.
 ///
@@ -54,6 +55,250 @@ pub fn normalize_infinity(z: Complex64) -> Complex64 {
     }
 }
 
+/// Tests whether a complex number is the point at infinity or beyond `threshold` in
+/// magnitude.
+///
+/// A variant of [`is_infinity`] for data where very large finite values should be
+/// treated as infinity, such as images of points near a pole. NaN is never infinity.
+///
+/// # Examples
+/// ```
+/// use mobius_applicatio::complex_utils::{is_infinity, is_infinity_eps};
+/// use num_complex::Complex64;
+///
+/// let z = Complex64::new(1e12, 0.0);
+/// assert!(is_infinity_eps(z, 1e10));
+/// assert!(!is_infinity(z));
+/// ```
+pub fn is_infinity_eps(z: Complex64, threshold: f64) -> bool {
+    is_infinity(z) || z.norm() > threshold
+}
+
+/// Normalizes the point at infinity and every value beyond `threshold` in magnitude to
+/// [`COMPLEX_INFINITY`].
+///
+/// A variant of [`normalize_infinity`] using [`is_infinity_eps`]; other values are
+/// returned unchanged.
+pub fn normalize_infinity_eps(z: Complex64, threshold: f64) -> Complex64 {
+    if is_infinity_eps(z, threshold) {
+        COMPLEX_INFINITY
+    } else {
+        z
+    }
+}
+
+/// Tests whether a complex number is a finite point of the plane.
+///
+/// Returns false for the point at infinity and for NaN, which represents no point at
+/// all. Useful for checking results after arithmetic or deserialization.
+///
+/// # Examples
+/// ```
+/// use mobius_applicatio::complex_utils::{is_finite_point, COMPLEX_INFINITY};
+/// use num_complex::Complex64;
+///
+/// assert!(is_finite_point(Complex64::new(1.0, 2.0)));
+/// assert!(!is_finite_point(COMPLEX_INFINITY));
+/// assert!(!is_finite_point(Complex64::new(f64::NAN, 0.0)));
+/// ```
+pub fn is_finite_point(z: Complex64) -> bool {
+    z.re.is_finite() && z.im.is_finite()
+}
+
+/// Returns the chordal distance between two points of the extended plane.
+///
+/// This is the Euclidean distance in R³ between their stereographic images on the
+/// unit sphere, 2|z1 - z2| / √((1 + |z1|²)(1 + |z2|²)), and 2 / √(1 + |z|²) from a
+/// finite point z to infinity. It is at most 2 (for antipodal points such as 0 and
+/// ∞), treats infinity like any other point, and is invariant under rotations of
+/// the sphere, so it suits tolerance checks near poles. When both points lie outside
+/// the unit circle the distance is computed from their reciprocals (the rotation
+/// z ↦ 1/z), which avoids overflow for huge points. NaN input gives NaN.
+///
+/// # Examples
+/// ```
+/// use mobius_applicatio::complex_utils::{chordal_distance, COMPLEX_INFINITY};
+/// use num_complex::Complex64;
+///
+/// let zero = Complex64::new(0.0, 0.0);
+/// assert!((chordal_distance(zero, COMPLEX_INFINITY) - 2.0).abs() < 1e-15);
+/// assert!(chordal_distance(Complex64::new(1e9, 0.0), COMPLEX_INFINITY) < 1e-8);
+/// ```
+pub fn chordal_distance(z1: Complex64, z2: Complex64) -> f64 {
+    if z1.is_nan() || z2.is_nan() {
+        return f64::NAN;
+    }
+    let (z1, z2) = if z1.norm() > 1.0 && z2.norm() > 1.0 {
+        (reciprocal(z1), reciprocal(z2))
+    } else {
+        (z1, z2)
+    };
+    match (is_infinity(z1), is_infinity(z2)) {
+        (true, true) => 0.0,
+        (true, false) => 2.0 / z2.norm().hypot(1.0),
+        (false, true) => 2.0 / z1.norm().hypot(1.0),
+        (false, false) => 2.0 * (z1 - z2).norm() / (z1.norm().hypot(1.0) * z2.norm().hypot(1.0)),
+    }
+}
+
+/// Returns how well separated three points of the extended plane are, from 0 to 1.
+///
+/// A Möbius transformation is determined by the images of three distinct points,
+/// and the map sending nearly coincident points to well separated ones has huge
+/// derivatives, so [`MobiusTransform::fit_least_squares`](crate::MobiusTransform::fit_least_squares)
+/// and similar constructions become ill-conditioned. This measure is the smallest
+/// pairwise [`chordal_distance`] divided by √3, its largest possible value (reached
+/// by three equally spaced points on a great circle of the sphere, such as the cube
+/// roots of unity). It is 0 when two points
+/// coincide, treats infinity like any other point, and is unchanged by rotations of
+/// the sphere. NaN input gives NaN.
+///
+/// # Examples
+/// ```
+/// use mobius_applicatio::complex_utils::{three_point_condition, COMPLEX_INFINITY};
+/// use num_complex::Complex64;
+///
+/// let (zero, one) = (Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0));
+/// assert!(three_point_condition(zero, one, COMPLEX_INFINITY) > 0.8);
+/// assert_eq!(three_point_condition(zero, one, one), 0.0);
+/// ```
+pub fn three_point_condition(z1: Complex64, z2: Complex64, z3: Complex64) -> f64 {
+    let distances = [chordal_distance(z1, z2), chordal_distance(z2, z3), chordal_distance(z1, z3)];
+    if distances.iter().any(|d| d.is_nan()) {
+        return f64::NAN;
+    }
+    distances.into_iter().fold(f64::INFINITY, f64::min) / 3.0_f64.sqrt()
+}
+
+/// Formats an array of points compactly, such as `[2-3i, ∞, 0.5]`.
+///
+/// Each point is written as in the `Display` output of
+/// [`MobiusTransform`](crate::MobiusTransform), rounded to 4 decimal places, with
+/// every representation of infinity (see [`is_infinity`]) shown as `∞` rather than
+/// the `inf+infi` printed for a raw `Array1<Complex64>`. Meant for logs and
+/// debugging output of batch operations such as
+/// [`MobiusTransform::apply_batch`](crate::MobiusTransform::apply_batch).
+///
+/// # Examples
+/// ```
+/// use mobius_applicatio::complex_utils::{format_point_array, COMPLEX_INFINITY};
+/// use ndarray::array;
+/// use num_complex::Complex64;
+///
+/// let points = array![Complex64::new(1.0, -0.5), COMPLEX_INFINITY];
+/// assert_eq!(format_point_array(&points), "[1-0.5i, ∞]");
+/// ```
+pub fn format_point_array(arr: &Array1<Complex64>) -> String {
+    let points: Vec<String> = arr.iter().map(|&z| format_complex(z, 4)).collect();
+    format!("[{}]", points.join(", "))
+}
+
+/// Returns 1/z, with 1/∞ = 0, without overflow for large z.
+fn reciprocal(z: Complex64) -> Complex64 {
+    if is_infinity(z) {
+        return Complex64::new(0.0, 0.0);
+    }
+    Complex64::from_polar(1.0 / z.norm(), -z.arg())
+}
+
+/// Formats a complex number compactly, such as `2-3i`, `i`, `-i`, `0` or `1`.
+///
+/// Each component is rounded to `precision` decimal places, with trailing zeros
+/// dropped; components that round to zero are omitted, and a unit imaginary part is
+/// written as `i`. The point at infinity is written `∞` and NaN as `NaN`. Shared by
+/// the human-readable output of the crate so that all of it looks the same; the exact
+/// round-trip format of [`MobiusTransform::to_coeff_string`](crate::MobiusTransform::to_coeff_string)
+/// is separate.
+pub(crate) fn format_complex(z: Complex64, precision: usize) -> String {
+    if z.is_nan() {
+        return "NaN".to_string();
+    }
+    if is_infinity(z) {
+        return "∞".to_string();
+    }
+
+    let real = format_component(z.re, precision);
+    let imaginary = format_component(z.im.abs(), precision);
+    if imaginary == "0" {
+        return real;
+    }
+    let sign = if z.im < 0.0 { "-" } else { "+" };
+    let imaginary = if imaginary == "1" { "i".to_string() } else { format!("{}i", imaginary) };
+    match (real.as_str(), sign) {
+        ("0", "+") => imaginary,
+        ("0", _) => format!("-{}", imaginary),
+        _ => format!("{}{}{}", real, sign, imaginary),
+    }
+}
+
+/// Formats a real number with at most `precision` decimals and no trailing zeros.
+pub(crate) fn format_component(x: f64, precision: usize) -> String {
+    let formatted = format!("{:.*}", precision, x);
+    let trimmed = if formatted.contains('.') {
+        formatted.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &formatted
+    };
+    // Values that round to zero may keep their sign, as in "-0"
+    if trimmed == "-0" {
+        "0".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// A point type that can be converted to and from a complex number.
+///
+/// Implement this for your own point or vector type to apply transformations to it
+/// directly with [`MobiusTransform::apply_point`](crate::MobiusTransform::apply_point).
+/// The point at infinity converts to [`COMPLEX_INFINITY`], so `from_complex` should
+/// accept infinite components.
+///
+/// # Examples
+/// ```
+/// use mobius_applicatio::complex_utils::ComplexPoint;
+/// use num_complex::Complex64;
+///
+/// struct Vec2 { x: f64, y: f64 }
+///
+/// impl ComplexPoint for Vec2 {
+///     fn to_complex(&self) -> Complex64 {
+///         Complex64::new(self.x, self.y)
+///     }
+///     fn from_complex(z: Complex64) -> Self {
+///         Vec2 { x: z.re, y: z.im }
+///     }
+/// }
+/// ```
+pub trait ComplexPoint {
+    /// Returns the point as a complex number x + iy.
+    fn to_complex(&self) -> Complex64;
+
+    /// Creates the point from a complex number x + iy.
+    fn from_complex(z: Complex64) -> Self;
+}
+
+impl ComplexPoint for Complex64 {
+    fn to_complex(&self) -> Complex64 {
+        *self
+    }
+
+    fn from_complex(z: Complex64) -> Self {
+        z
+    }
+}
+
+/// The tuple (x, y) is the point x + iy.
+impl ComplexPoint for (f64, f64) {
+    fn to_complex(&self) -> Complex64 {
+        Complex64::new(self.0, self.1)
+    }
+
+    fn from_complex(z: Complex64) -> Self {
+        (z.re, z.im)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,4 +330,126 @@ mod tests {
         let z = Complex64::new(1.0, 2.0);
         assert_eq!(normalize_infinity(z), z);
     }
+
+    #[test]
+    fn test_complex_point_round_trip() {
+        let point = (1.5, -2.0);
+        assert_eq!(point.to_complex(), Complex64::new(1.5, -2.0));
+        assert_eq!(<(f64, f64)>::from_complex(point.to_complex()), point);
+
+        let z = Complex64::new(0.25, 3.0);
+        assert_eq!(Complex64::from_complex(z.to_complex()), z);
+        assert!(is_infinity(<(f64, f64)>::from_complex(COMPLEX_INFINITY).to_complex()));
+    }
+
+    #[test]
+    fn test_is_finite_point() {
+        assert!(is_finite_point(Complex64::new(0.0, 0.0)));
+        assert!(is_finite_point(Complex64::new(-1e300, 1e-300)));
+        assert!(!is_finite_point(COMPLEX_INFINITY));
+        assert!(!is_finite_point(Complex64::new(1.0, f64::NEG_INFINITY)));
+        assert!(!is_finite_point(Complex64::new(f64::NAN, 1.0)));
+        assert!(!is_finite_point(Complex64::new(f64::NAN, f64::INFINITY)));
+    }
+
+    #[test]
+    fn test_format_complex() {
+        // Purely real
+        assert_eq!(format_complex(Complex64::new(2.5, 0.0), 3), "2.5");
+        assert_eq!(format_complex(Complex64::new(-4.0, 0.0), 3), "-4");
+        // Purely imaginary
+        assert_eq!(format_complex(Complex64::new(0.0, 3.0), 3), "3i");
+        assert_eq!(format_complex(Complex64::new(0.0, -0.25), 3), "-0.25i");
+        // Negative imaginary part
+        assert_eq!(format_complex(Complex64::new(2.0, -3.0), 3), "2-3i");
+        assert_eq!(format_complex(Complex64::new(-1.5, 2.0), 3), "-1.5+2i");
+        // Zero, including values that round to zero
+        assert_eq!(format_complex(Complex64::new(0.0, 0.0), 3), "0");
+        assert_eq!(format_complex(Complex64::new(-0.0, -1e-9), 3), "0");
+        // Units
+        assert_eq!(format_complex(Complex64::new(1.0, 0.0), 3), "1");
+        assert_eq!(format_complex(Complex64::new(0.0, 1.0), 3), "i");
+        assert_eq!(format_complex(Complex64::new(0.0, -1.0), 3), "-i");
+        assert_eq!(format_complex(Complex64::new(1.0, 1.0), 3), "1+i");
+        // Rounding to the precision
+        assert_eq!(format_complex(Complex64::new(1.23456, -0.99999), 2), "1.23-i");
+        assert_eq!(format_complex(Complex64::new(7.0, 0.4), 0), "7");
+        // Special values
+        assert_eq!(format_complex(COMPLEX_INFINITY, 3), "∞");
+        assert_eq!(format_complex(Complex64::new(f64::NAN, 0.0), 3), "NaN");
+    }
+
+    #[test]
+    fn test_three_point_condition() {
+        let roots: Vec<Complex64> = (0..3)
+            .map(|k| Complex64::from_polar(1.0, 2.0 * std::f64::consts::PI * k as f64 / 3.0))
+            .collect();
+        assert!((three_point_condition(roots[0], roots[1], roots[2]) - 1.0).abs() < 1e-12);
+
+        let zero = Complex64::new(0.0, 0.0);
+        let one = Complex64::new(1.0, 0.0);
+        let well_separated = three_point_condition(zero, one, COMPLEX_INFINITY);
+        assert!(well_separated > 0.8);
+
+        let nearly_equal = three_point_condition(zero, one, Complex64::new(1.0, 1e-6));
+        assert!(nearly_equal < 1e-6);
+        assert!(nearly_equal > 0.0);
+        assert_eq!(three_point_condition(one, zero, one), 0.0);
+        assert_eq!(three_point_condition(COMPLEX_INFINITY, one, COMPLEX_INFINITY), 0.0);
+        assert!(three_point_condition(zero, one, Complex64::new(f64::NAN, 0.0)).is_nan());
+    }
+
+    #[test]
+    fn test_format_point_array() {
+        let points = ndarray::array![
+            Complex64::new(2.0, -3.0),
+            COMPLEX_INFINITY,
+            Complex64::new(f64::NEG_INFINITY, 0.0),
+            Complex64::new(1.0 / 3.0, 0.0),
+        ];
+        assert_eq!(format_point_array(&points), "[2-3i, ∞, ∞, 0.3333]");
+        assert_eq!(format_point_array(&Array1::from(vec![])), "[]");
+    }
+
+    #[test]
+    fn test_chordal_distance() {
+        let zero = Complex64::new(0.0, 0.0);
+        let one = Complex64::new(1.0, 0.0);
+        assert!((chordal_distance(zero, COMPLEX_INFINITY) - 2.0).abs() < 1e-15);
+        assert!((chordal_distance(one, COMPLEX_INFINITY) - 2.0_f64.sqrt()).abs() < 1e-15);
+        assert!((chordal_distance(zero, one) - 2.0_f64.sqrt()).abs() < 1e-15);
+        assert_eq!(chordal_distance(COMPLEX_INFINITY, COMPLEX_INFINITY), 0.0);
+        assert!(chordal_distance(one, Complex64::new(f64::NAN, 0.0)).is_nan());
+
+        // Agrees with the Euclidean distance between the points on the sphere
+        let to_sphere = |z: Complex64| {
+            let d = 1.0 + z.norm_sqr();
+            [2.0 * z.re / d, 2.0 * z.im / d, (z.norm_sqr() - 1.0) / d]
+        };
+        let (z1, z2) = (Complex64::new(0.3, -2.0), Complex64::new(-1.5, 0.7));
+        let (p1, p2) = (to_sphere(z1), to_sphere(z2));
+        let euclidean = (0..3).map(|k| (p1[k] - p2[k]).powi(2)).sum::<f64>().sqrt();
+        assert!((chordal_distance(z1, z2) - euclidean).abs() < 1e-14);
+        assert_eq!(chordal_distance(z1, z2), chordal_distance(z2, z1));
+
+        // Finite, and accurate, far from the origin
+        let (w1, w2) = (Complex64::new(1e200, 0.0), Complex64::new(2e200, 0.0));
+        assert!((chordal_distance(w1, w2) / 1e-200 - 1.0).abs() < 1e-12);
+        assert!(chordal_distance(w1, COMPLEX_INFINITY) > 0.0);
+    }
+
+    #[test]
+    fn test_infinity_with_threshold() {
+        let large = Complex64::new(3e12, -4e12);
+        assert!(is_infinity_eps(large, 1e10));
+        assert!(!is_infinity(large));
+        assert_eq!(normalize_infinity_eps(large, 1e10), COMPLEX_INFINITY);
+        assert_eq!(normalize_infinity(large), large);
+
+        // Below the threshold, and special values
+        assert!(!is_infinity_eps(large, 1e13));
+        assert_eq!(normalize_infinity_eps(large, 1e13), large);
+        assert!(is_infinity_eps(Complex64::new(f64::NEG_INFINITY, 0.0), 1e10));
+        assert!(!is_infinity_eps(Complex64::new(f64::NAN, 0.0), 1e10));
+    }
 }
@@ -0,0 +1,141 @@
+//! Reading named transformations from TOML configuration.
+//!
+//! Enabled with the `config` feature.
+
+use std::collections::HashMap;
+use num_complex::Complex64;
+use toml::{Table, Value};
+use crate::transforms::{MobiusTransform, TransformError};
+
+/// Parses a TOML document defining named transformations.
+///
+/// Each top-level table is one transformation, named by its key, with the
+/// coefficients `a`, `b`, `c` and `d` given as inline tables `{ re, im }`. Both parts
+/// are required and may be integers or floats:
+///
+/// ```toml
+/// [inversion]
+/// a = { re = 0, im = 0 }
+/// b = { re = 1, im = 0 }
+/// c = { re = 1, im = 0 }
+/// d = { re = 0, im = 0 }
+/// ```
+///
+/// Each entry is validated as in [`MobiusTransform::new`], and the first invalid
+/// entry fails the whole document.
+///
+/// # Errors
+/// Returns `TransformError::InvalidFormat` if the document is not valid TOML or an
+/// entry is malformed (a missing, duplicate or unknown coefficient, or a value that
+/// is not a number); the message names the entry.
+/// Returns `TransformError::InfiniteCoefficient` or `TransformError::SingularTransform`
+/// if the coefficients of an entry do not form a valid transformation.
+///
+/// # Examples
+/// ```
+/// use mobius_applicatio::config::transforms_from_toml;
+/// use num_complex::Complex64;
+///
+/// let transforms = transforms_from_toml(
+///     "[shift]\na = { re = 1, im = 0 }\nb = { re = 0.5, im = -1 }\nc = { re = 0, im = 0 }\nd = { re = 1, im = 0 }\n",
+/// ).unwrap();
+/// let shift = &transforms["shift"];
+/// assert!((shift.apply(Complex64::new(0.0, 0.0)) - Complex64::new(0.5, -1.0)).norm() < 1e-12);
+/// ```
+pub fn transforms_from_toml(s: &str) -> Result<HashMap<String, MobiusTransform>, TransformError> {
+    let document: Table = s
+        .parse()
+        .map_err(|error: toml::de::Error| TransformError::InvalidFormat(error.message().to_string()))?;
+
+    document
+        .iter()
+        .map(|(name, entry)| {
+            let entry = entry.as_table().ok_or_else(|| {
+                TransformError::InvalidFormat(format!("`{}` is not a table", name))
+            })?;
+            if let Some(key) = entry.keys().find(|key| !matches!(key.as_str(), "a" | "b" | "c" | "d")) {
+                return Err(TransformError::InvalidFormat(format!("unknown coefficient `{}` in `{}`", key, name)));
+            }
+            let coefficient = |key: &str| {
+                let value = entry.get(key).ok_or_else(|| {
+                    TransformError::InvalidFormat(format!("missing coefficient `{}` in `{}`", key, name))
+                })?;
+                parse_complex(value).ok_or_else(|| {
+                    TransformError::InvalidFormat(format!("coefficient `{}` in `{}` must be {{ re, im }}", key, name))
+                })
+            };
+            let transform = MobiusTransform::new(coefficient("a")?, coefficient("b")?, coefficient("c")?, coefficient("d")?)?;
+            Ok((name.clone(), transform))
+        })
+        .collect()
+}
+
+/// Parses an inline table `{ re, im }` with numeric parts.
+fn parse_complex(value: &Value) -> Option<Complex64> {
+    let table = value.as_table()?;
+    if table.len() != 2 {
+        return None;
+    }
+    let part = |key: &str| match table.get(key)? {
+        Value::Integer(n) => Some(*n as f64),
+        Value::Float(x) => Some(*x),
+        _ => None,
+    };
+    Some(Complex64::new(part("re")?, part("im")?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROTATION_AND_SHIFT: &str = r#"
+        [rotation]
+        a = { re = 0, im = 1 }
+        b = { re = 0, im = 0 }
+        c = { re = 0, im = 0 }
+        d = { re = 1, im = 0 }
+
+        [shift]
+        a = { re = 1.0, im = 0.0 }
+        b = { re = 2.5, im = -1.0 }
+        c = { re = 0.0, im = 0.0 }
+        d = { re = 1.0, im = 0.0 }
+    "#;
+
+    #[test]
+    fn test_transforms_from_toml() {
+        let transforms = transforms_from_toml(ROTATION_AND_SHIFT).unwrap();
+        assert_eq!(transforms.len(), 2);
+        let z = Complex64::new(1.0, 2.0);
+        assert!((transforms["rotation"].apply(z) - Complex64::new(-2.0, 1.0)).norm() < 1e-12);
+        assert!((transforms["shift"].apply(z) - Complex64::new(3.5, 1.0)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_transforms_from_toml_rejects_singular_entry() {
+        let document = format!(
+            "{}\n[singular]\na = {{ re = 1, im = 0 }}\nb = {{ re = 2, im = 0 }}\nc = {{ re = 2, im = 0 }}\nd = {{ re = 4, im = 0 }}\n",
+            ROTATION_AND_SHIFT
+        );
+        assert_eq!(transforms_from_toml(&document), Err(TransformError::SingularTransform));
+    }
+
+    #[test]
+    fn test_transforms_from_toml_rejects_malformed_entries() {
+        let malformed = [
+            "[m]\na = { re = 1, im = 0 }\nb = { re = 0, im = 0 }\nc = { re = 0, im = 0 }\n",
+            "[m]\na = { re = 1 }\nb = { re = 0, im = 0 }\nc = { re = 0, im = 0 }\nd = { re = 1, im = 0 }\n",
+            "[m]\na = 1\nb = { re = 0, im = 0 }\nc = { re = 0, im = 0 }\nd = { re = 1, im = 0 }\n",
+            "[m]\na = { re = 1, im = 0 }\nb = { re = 0, im = 0 }\nc = { re = 0, im = 0 }\nd = { re = 1, im = 0 }\ne = { re = 0, im = 0 }\n",
+            "m = 3\n",
+            "[m\n",
+        ];
+        for document in malformed {
+            assert!(
+                matches!(transforms_from_toml(document), Err(TransformError::InvalidFormat(_))),
+                "{}",
+                document
+            );
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! Fitting Möbius transformations to point correspondences.
+
+use num_complex::Complex64;
+use crate::transforms::{MobiusTransform, TransformError};
+
+/// Number of coefficients (a, b, c, d) being fitted.
+const COEFFICIENTS: usize = 4;
+
+/// Maximum number of Jacobi sweeps; convergence normally takes fewer than ten.
+const MAX_SWEEPS: usize = 60;
+
+/// Relative size below which a singular value is treated as zero.
+const RANK_TOLERANCE: f64 = 1e-12;
+
+impl MobiusTransform {
+    /// Fits the transformation that best maps each `src[i]` to `dst[i]`.
+    ///
+    /// Each correspondence z ↦ w gives the linear equation az + b - czw - dw = 0 in the
+    /// coefficients. The fit minimizes the sum of squared residuals of these equations
+    /// over coefficient vectors of unit norm, which is the right singular vector of the
+    /// smallest singular value of the stacked system. The points are first scaled to
+    /// unit root-mean-square magnitude to balance the columns.
+    ///
+    /// Three correspondences determine a transformation exactly, and for exact data
+    /// the fit recovers it. With more, noisy correspondences the result minimizes an
+    /// algebraic error, which is close to the geometric error |f(z) - w| when the
+    /// points stay away from the pole.
+    ///
+    /// # Errors
+    /// Returns `TransformError::ShapeMismatch` if the slices differ in length.
+    /// Returns `TransformError::InvalidArgument` if there are fewer than three
+    /// correspondences, a point is infinite or NaN, or the data do not determine a
+    /// unique transformation (e.g. repeated points).
+    /// Returns `TransformError::SingularTransform` if the best fit is degenerate.
+    pub fn fit_least_squares(src: &[Complex64], dst: &[Complex64]) -> Result<MobiusTransform, TransformError> {
+        if src.len() != dst.len() {
+            return Err(TransformError::ShapeMismatch {
+                expected: vec![src.len()],
+                found: vec![dst.len()],
+            });
+        }
+        if src.len() < 3 {
+            return Err(TransformError::InvalidArgument(
+                "at least three correspondences are required".to_string(),
+            ));
+        }
+        if src.iter().chain(dst).any(|z| !z.is_finite()) {
+            return Err(TransformError::InvalidArgument(
+                "correspondences must be finite".to_string(),
+            ));
+        }
+
+        let src_scale = rms_norm(src);
+        let dst_scale = rms_norm(dst);
+        let rows: Vec<[Complex64; COEFFICIENTS]> = src
+            .iter()
+            .zip(dst)
+            .map(|(&z, &w)| {
+                let (z, w) = (z / src_scale, w / dst_scale);
+                [z, Complex64::new(1.0, 0.0), -z * w, -w]
+            })
+            .collect();
+
+        let [a, b, c, d] = smallest_right_singular_vector(&rows).ok_or_else(|| {
+            TransformError::InvalidArgument("correspondences do not determine a unique transformation".to_string())
+        })?;
+
+        // Undo the scaling: f(z) = dst_scale · g(z / src_scale)
+        MobiusTransform::new(a * dst_scale / src_scale, b * dst_scale, c / src_scale, d)
+    }
+}
+
+/// Returns the root-mean-square magnitude of the points, or 1 if they are all zero.
+fn rms_norm(points: &[Complex64]) -> f64 {
+    let rms = (points.iter().map(|z| z.norm_sqr()).sum::<f64>() / points.len() as f64).sqrt();
+    if rms > 0.0 { rms } else { 1.0 }
+}
+
+/// Returns a unit vector x minimizing ‖Ax‖ for the complex matrix with the given rows.
+///
+/// Runs one-sided (Hestenes) Jacobi on the real embedding [[Re A, -Im A], [Im A, Re A]],
+/// which avoids squaring the condition number as the normal equations would. Every
+/// singular value of A appears twice in the embedding, with right singular vectors
+/// (u, v) and (-v, u) both encoding u + iv up to a phase. Returns `None` if the
+/// minimizer is not unique, i.e. the second-smallest singular value of A is also zero.
+fn smallest_right_singular_vector(rows: &[[Complex64; COEFFICIENTS]]) -> Option<[Complex64; COEFFICIENTS]> {
+    const N: usize = 2 * COEFFICIENTS;
+
+    // Columns of the real embedding, each of length 2m
+    let mut columns: Vec<Vec<f64>> = (0..N)
+        .map(|j| {
+            let (k, imaginary_block) = (j % COEFFICIENTS, j >= COEFFICIENTS);
+            let top = rows.iter().map(|row| if imaginary_block { -row[k].im } else { row[k].re });
+            let bottom = rows.iter().map(|row| if imaginary_block { row[k].re } else { row[k].im });
+            top.chain(bottom).collect()
+        })
+        .collect();
+    let mut v = [[0.0; N]; N];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..N {
+            for q in (p + 1)..N {
+                let alpha: f64 = columns[p].iter().map(|x| x * x).sum();
+                let beta: f64 = columns[q].iter().map(|x| x * x).sum();
+                let gamma: f64 = columns[p].iter().zip(&columns[q]).map(|(x, y)| x * y).sum();
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                // Rotation that makes columns p and q orthogonal
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                let cos = 1.0 / (1.0 + t * t).sqrt();
+                let sin = cos * t;
+
+                for i in 0..columns[p].len() {
+                    let (x, y) = (columns[p][i], columns[q][i]);
+                    columns[p][i] = cos * x - sin * y;
+                    columns[q][i] = sin * x + cos * y;
+                }
+                for row in v.iter_mut() {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = cos * x - sin * y;
+                    row[q] = sin * x + cos * y;
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    // Singular values are the column norms; sort ascending
+    let mut order: Vec<(f64, usize)> = columns
+        .iter()
+        .enumerate()
+        .map(|(j, column)| (column.iter().map(|x| x * x).sum::<f64>().sqrt(), j))
+        .collect();
+    order.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+    // The smallest singular value of A occupies the first two slots of the embedding
+    let largest = order[N - 1].0;
+    if largest == 0.0 || order[2].0 <= RANK_TOLERANCE * largest {
+        return None;
+    }
+
+    let j = order[0].1;
+    let vector: [Complex64; COEFFICIENTS] = std::array::from_fn(|k| Complex64::new(v[k][j], v[k + COEFFICIENTS][j]));
+    Some(vector)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_transform() -> MobiusTransform {
+        MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap()
+    }
+
+    fn sample_points() -> Vec<Complex64> {
+        (0..12)
+            .map(|k| Complex64::from_polar(0.5 + 0.2 * k as f64, 0.9 * k as f64))
+            .collect()
+    }
+
+    #[test]
+    fn test_fit_exact_correspondences() {
+        let m = sample_transform();
+        let src = sample_points();
+        let dst: Vec<Complex64> = src.iter().map(|&z| m.apply(z)).collect();
+
+        let fitted = MobiusTransform::fit_least_squares(&src, &dst).unwrap();
+        assert!(fitted.approx_eq_with_tolerance(&m, 1e-8));
+
+        // Three points suffice
+        let fitted = MobiusTransform::fit_least_squares(&src[..3], &dst[..3]).unwrap();
+        assert!(fitted.approx_eq_with_tolerance(&m, 1e-8));
+    }
+
+    #[test]
+    fn test_fit_noisy_correspondences() {
+        let m = sample_transform();
+        let src = sample_points();
+        // Deterministic perturbations of size 1e-4
+        let dst: Vec<Complex64> = src
+            .iter()
+            .enumerate()
+            .map(|(k, &z)| m.apply(z) + Complex64::from_polar(1e-4, 2.3 * k as f64))
+            .collect();
+
+        let fitted = MobiusTransform::fit_least_squares(&src, &dst).unwrap();
+        let max_residual = src
+            .iter()
+            .zip(&dst)
+            .map(|(&z, &w)| (fitted.apply(z) - w).norm())
+            .fold(0.0, f64::max);
+        assert!(max_residual < 1e-3, "residual {}", max_residual);
+        assert!(fitted.distance(&m) < 1e-2);
+    }
+
+    #[test]
+    fn test_fit_invalid_data() {
+        let z = Complex64::new(1.0, 0.0);
+        let points = [z, 2.0 * z, 3.0 * z];
+
+        assert!(matches!(
+            MobiusTransform::fit_least_squares(&points, &points[..2]),
+            Err(TransformError::ShapeMismatch { .. })
+        ));
+        assert!(matches!(
+            MobiusTransform::fit_least_squares(&points[..2], &points[..2]),
+            Err(TransformError::InvalidArgument(_))
+        ));
+
+        // A repeated source point leaves the map undetermined
+        assert!(matches!(
+            MobiusTransform::fit_least_squares(&[z, z, z], &points),
+            Err(TransformError::InvalidArgument(_))
+        ));
+
+        let infinite = [z, 2.0 * z, crate::complex_utils::COMPLEX_INFINITY];
+        assert!(MobiusTransform::fit_least_squares(&infinite, &points).is_err());
+    }
+}
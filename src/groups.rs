@@ -0,0 +1,217 @@
+//! Discrete groups generated by Möbius transformations.
+//!
+//! Utilities for exploring the groups (such as Kleinian groups) generated by a
+//! finite set of transformations.
+
+use num_complex::Complex64;
+use crate::transforms::MobiusTransform;
+
+/// Enumerates the group elements given by words of length at most `max_depth`.
+///
+/// Starting from the identity, the search composes breadth-first with each generator
+/// and each generator's inverse. Elements that are approximately equal (up to scalar,
+/// see [`MobiusTransform::approx_eq`]) to one already found are discarded, so words
+/// that reduce to the same map, such as g ∘ g⁻¹, only appear once. The identity
+/// (the empty word) is always included.
+///
+/// For k generators a free group has up to 1 + 2k((2k - 1)^max_depth - 1)/(2k - 2)
+/// elements of word length at most `max_depth`, so the output grows exponentially
+/// with depth, and de-duplication is quadratic in the number of elements found.
+/// Callers should keep `max_depth` small.
+///
+/// # Arguments
+/// * `generators` - The generating transformations
+/// * `max_depth` - The maximum word length
+pub fn generate_group(generators: &[MobiusTransform], max_depth: usize) -> Vec<MobiusTransform> {
+    let letters: Vec<MobiusTransform> = generators
+        .iter()
+        .flat_map(|g| [*g, g.inverse()])
+        .collect();
+
+    let mut elements = vec![MobiusTransform::identity()];
+    let mut frontier = elements.clone();
+
+    for _ in 0..max_depth {
+        let mut next_frontier = Vec::new();
+        for word in &frontier {
+            for letter in &letters {
+                let candidate = word.compose(letter);
+                if !elements.iter().any(|existing| existing.approx_eq(&candidate)) {
+                    elements.push(candidate);
+                    next_frontier.push(candidate);
+                }
+            }
+        }
+        if next_frontier.is_empty() {
+            break; // The group is finite and fully enumerated
+        }
+        frontier = next_frontier;
+    }
+
+    elements
+}
+
+/// Returns the isometric circles of each generator and its inverse, as (center, radius).
+///
+/// For each generator g the circles of g and g⁻¹ are returned in that order (see
+/// [`MobiusTransform::isometric_circle`] and
+/// [`MobiusTransform::isometric_circle_of_inverse`]). g maps the first onto the second.
+/// When no element of the group fixes infinity other than the identity, the Ford
+/// domain is the region outside all isometric circles of the group; the circles of
+/// the generators bound a first approximation of it.
+///
+/// Generators with normalized c below the tolerance (affine maps, which fix infinity)
+/// have no isometric circle and are skipped.
+pub fn ford_domain_circles(generators: &[MobiusTransform]) -> Vec<(Complex64, f64)> {
+    generators
+        .iter()
+        .filter_map(|g| Some([g.isometric_circle()?, g.isometric_circle_of_inverse()?]))
+        .flatten()
+        .collect()
+}
+
+/// Number of initial iterates discarded by [`limit_set_points`].
+const TRANSIENT_ITERATIONS: usize = 10;
+
+/// Approximates the limit set of the group by iterating random words on a seed point.
+///
+/// This is an approximate renderer in the style of the chaos game: starting from
+/// `seed_point`, each step applies a randomly chosen generator or inverse, never the
+/// inverse of the previous letter, so the point follows a random infinite reduced
+/// word. For a discrete group whose limit set does not contain the seed, the orbit
+/// approaches the limit set exponentially, and the iterates accumulate densely on it.
+/// The first few iterates, still on their way, are discarded; the finite images of
+/// the remaining `iterations` minus ten steps are returned in order.
+///
+/// The words come from a fixed pseudo-random sequence, so the output is
+/// reproducible. Coverage of the limit set is uneven: parts reached by strongly
+/// contracting words are visited rarely.
+pub fn limit_set_points(generators: &[MobiusTransform], iterations: usize, seed_point: Complex64) -> Vec<Complex64> {
+    let letters: Vec<MobiusTransform> = generators
+        .iter()
+        .flat_map(|g| [*g, g.inverse()])
+        .collect();
+    if letters.is_empty() {
+        return Vec::new();
+    }
+
+    let mut state = 0x9E37_79B9_7F4A_7C15_u64;
+    let mut previous: Option<usize> = None;
+    let mut z = seed_point;
+    let mut points = Vec::with_capacity(iterations.saturating_sub(TRANSIENT_ITERATIONS));
+
+    for step in 0..iterations {
+        // The inverse of letter i is letter i ^ 1; skip it to keep the word reduced
+        let choices = if previous.is_some() && letters.len() > 1 { letters.len() - 1 } else { letters.len() };
+        let mut index = (next_random(&mut state) % choices as u64) as usize;
+        if let Some(last) = previous {
+            if letters.len() > 1 && index >= (last ^ 1) {
+                index += 1;
+            }
+        }
+        previous = Some(index);
+
+        z = letters[index].apply(z);
+        if step >= TRANSIENT_ITERATIONS && z.is_finite() {
+            points.push(z);
+        }
+    }
+    points
+}
+
+/// Advances a xorshift64* generator and returns its next output.
+fn next_random(state: &mut u64) -> u64 {
+    *state ^= *state >> 12;
+    *state ^= *state << 25;
+    *state ^= *state >> 27;
+    state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schottky_generators() -> [MobiusTransform; 2] {
+        // z ↦ 3z fixes 0 and ∞; z ↦ (2z + 1) / (z + 2) fixes 1 and -1
+        let a = MobiusTransform::new(
+            Complex64::new(3.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        let b = MobiusTransform::new(
+            Complex64::new(2.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(2.0, 0.0),
+        ).unwrap();
+        [a, b]
+    }
+
+    #[test]
+    fn test_generate_group_depth_two() {
+        let elements = generate_group(&schottky_generators(), 2);
+
+        // Free group on two generators: 1 + 4 + 4·3 reduced words of length ≤ 2
+        assert_eq!(elements.len(), 17);
+
+        for (i, m1) in elements.iter().enumerate() {
+            for m2 in &elements[i + 1..] {
+                assert!(!m1.approx_eq(m2));
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_group_finite_group() {
+        // z ↦ iz generates the cyclic group of order 4
+        let rotation = MobiusTransform::new(
+            Complex64::new(0.0, 1.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        assert_eq!(generate_group(&[rotation], 10).len(), 4);
+    }
+
+    #[test]
+    fn test_ford_domain_circles() {
+        let [a, b] = schottky_generators();
+        // z ↦ 3z is affine and has no isometric circle
+        assert!(ford_domain_circles(&[a]).is_empty());
+
+        // z ↦ (2z + 1) / (z + 2) has determinant 3, so normalized c = 1/√3: circles of
+        // radius √3 centered at -2 (the pole) and 2 (the image of infinity)
+        let circles = ford_domain_circles(&[a, b]);
+        assert_eq!(circles.len(), 2);
+        let (center, radius) = circles[0];
+        assert!((center - Complex64::new(-2.0, 0.0)).norm() < 1e-10);
+        assert!((radius - 3.0_f64.sqrt()).abs() < 1e-10);
+        let (center, radius) = circles[1];
+        assert!((center - Complex64::new(2.0, 0.0)).norm() < 1e-10);
+        assert!((radius - 3.0_f64.sqrt()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_limit_set_points_stay_bounded() {
+        // Hyperbolic automorphisms of the unit disk with axes along both coordinate
+        // axes; their isometric circles are disjoint, so they generate a Schottky
+        // group whose limit set is a Cantor set on the unit circle
+        let one = Complex64::new(1.0, 0.0);
+        let p = Complex64::new(0.9, 0.0);
+        let q = Complex64::new(0.0, 0.9);
+        let a = MobiusTransform::new(one, p, p.conj(), one).unwrap();
+        let b = MobiusTransform::new(one, q, q.conj(), one).unwrap();
+
+        let points = limit_set_points(&[a, b], 2000, Complex64::new(0.0, 0.0));
+        assert_eq!(points.len(), 2000 - TRANSIENT_ITERATIONS);
+        assert!(points.iter().all(|z| z.norm() < 1.0 + 1e-9));
+        // The iterates accumulate on the limit set
+        let near_circle = points.iter().filter(|z| z.norm() > 0.99).count();
+        assert!(near_circle > points.len() * 9 / 10);
+
+        // Reproducible
+        assert_eq!(points, limit_set_points(&[a, b], 2000, Complex64::new(0.0, 0.0)));
+        assert!(limit_set_points(&[], 100, one).is_empty());
+    }
+}
@@ -0,0 +1,265 @@
+//! Hyperbolic geometry in the Poincaré disk and upper half-plane models.
+//!
+//! The orientation-preserving isometries of both models are Möbius transformations:
+//! those preserving the unit disk and those with real coefficients (PSL(2,R)),
+//! respectively.
+
+use num_complex::Complex64;
+use crate::transforms::{MobiusTransform, TransformError};
+
+/// A model of the hyperbolic plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Model {
+    /// The Poincaré disk |z| < 1.
+    Disk,
+    /// The upper half-plane Im z > 0.
+    HalfPlane,
+}
+
+impl Model {
+    /// Tests whether a point lies in the (open) model.
+    pub fn contains(&self, z: Complex64) -> bool {
+        match self {
+            Model::Disk => z.norm() < 1.0,
+            Model::HalfPlane => z.im > 0.0 && z.re.is_finite() && z.im.is_finite(),
+        }
+    }
+
+    /// Returns the hyperbolic distance between two points of the model.
+    ///
+    /// See [`disk_distance`] and [`half_plane_distance`].
+    pub fn distance(&self, z1: Complex64, z2: Complex64) -> f64 {
+        match self {
+            Model::Disk => disk_distance(z1, z2),
+            Model::HalfPlane => half_plane_distance(z1, z2),
+        }
+    }
+
+    /// Points of the model used by [`MobiusTransform::is_isometry`].
+    fn sample_points(&self) -> [Complex64; 5] {
+        match self {
+            Model::Disk => [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.5, 0.0),
+                Complex64::new(-0.3, 0.4),
+                Complex64::new(0.0, -0.7),
+                Complex64::new(-0.6, -0.2),
+            ],
+            Model::HalfPlane => [
+                Complex64::new(0.0, 1.0),
+                Complex64::new(1.0, 2.0),
+                Complex64::new(-0.5, 0.3),
+                Complex64::new(2.0, 0.1),
+                Complex64::new(-3.0, 4.0),
+            ],
+        }
+    }
+}
+
+/// Returns the hyperbolic distance between two points of the Poincaré disk.
+///
+/// Uses d = 2 artanh(|z1 - z2| / |1 - z̄1 z2|), which is accurate for nearby points.
+/// Returns NaN if either point is outside the open unit disk.
+pub fn disk_distance(z1: Complex64, z2: Complex64) -> f64 {
+    if !Model::Disk.contains(z1) || !Model::Disk.contains(z2) {
+        return f64::NAN;
+    }
+    let ratio = (z1 - z2).norm() / (1.0 - z1.conj() * z2).norm();
+    2.0 * ratio.atanh()
+}
+
+/// Returns the hyperbolic distance between two points of the upper half-plane.
+///
+/// Uses d = 2 artanh(|z1 - z2| / |z1 - z̄2|), which is accurate for nearby points.
+/// Returns NaN if either point is outside the open upper half-plane.
+pub fn half_plane_distance(z1: Complex64, z2: Complex64) -> f64 {
+    if !Model::HalfPlane.contains(z1) || !Model::HalfPlane.contains(z2) {
+        return f64::NAN;
+    }
+    let ratio = (z1 - z2).norm() / (z1 - z2.conj()).norm();
+    2.0 * ratio.atanh()
+}
+
+impl MobiusTransform {
+    /// Creates the map from the Poincaré disk to the upper half-plane,
+    /// f(z) = i(1 + z) / (1 - z).
+    ///
+    /// It sends the unit circle to the real axis, with 1 ↦ ∞, -1 ↦ 0 and 0 ↦ i, and
+    /// carries hyperbolic distances in the disk to those in the half-plane. Its inverse
+    /// is [`MobiusTransform::half_plane_to_disk`].
+    pub fn disk_to_half_plane() -> Self {
+        let i = Complex64::new(0.0, 1.0);
+        Self::new(i, i, Complex64::new(-1.0, 0.0), Complex64::new(1.0, 0.0))
+            .expect("Disk to half-plane map should always be valid")
+    }
+
+    /// Creates the Cayley transform from the upper half-plane to the Poincaré disk,
+    /// f(z) = (z - i) / (z + i).
+    ///
+    /// It sends the real axis to the unit circle and i to 0. Its inverse is
+    /// [`MobiusTransform::disk_to_half_plane`].
+    pub fn half_plane_to_disk() -> Self {
+        let i = Complex64::new(0.0, 1.0);
+        let one = Complex64::new(1.0, 0.0);
+        Self::new(one, -i, one, i).expect("Cayley transform should always be valid")
+    }
+
+    /// Projects the transformation onto the nearest map with real coefficients.
+    ///
+    /// The coefficients are only defined up to a common complex factor, so they are
+    /// first normalized to determinant one and rotated by the common phase e^{-iφ}
+    /// that makes them as close to real as possible (φ = arg(a² + b² + c² + d²) / 2
+    /// minimizes the sum of squared imaginary parts). The real parts then give the
+    /// projection. For a small perturbation of a half-plane isometry (PSL(2,R)) the
+    /// result is that isometry, up to an error of the size of the perturbation.
+    ///
+    /// # Errors
+    /// Returns `TransformError::SingularTransform` if the real parts have a vanishing
+    /// determinant, which happens when the map is far from any real-coefficient map
+    /// (for example, the real parts of (z + 1) / (iz + 2i) form a singular matrix).
+    pub fn project_to_real(&self) -> Result<MobiusTransform, TransformError> {
+        let (a, b, c, d) = self.normalize().coefficients();
+        let phase = Complex64::from_polar(1.0, -(a * a + b * b + c * c + d * d).arg() / 2.0);
+        let real = |z: Complex64| Complex64::new((z * phase).re, 0.0);
+        MobiusTransform::new(real(a), real(b), real(c), real(d))
+    }
+
+    /// Tests whether the transformation is an isometry of the given model.
+    ///
+    /// The check is numerical: the transformation must map a fixed set of sample
+    /// points into the model and preserve all their pairwise hyperbolic distances,
+    /// within `tol` relative to max(1, distance). Any Möbius map preserving these
+    /// distances maps the model onto itself, since it is determined by the images
+    /// of three points.
+    pub fn is_isometry(&self, model: Model, tol: f64) -> bool {
+        let points = model.sample_points();
+        let images = points.map(|z| self.apply(z));
+
+        for i in 0..points.len() {
+            for j in (i + 1)..points.len() {
+                let before = model.distance(points[i], points[j]);
+                let after = model.distance(images[i], images[j]);
+                // An image outside the model gives a NaN distance
+                if after.is_nan() || (before - after).abs() > tol * before.max(1.0) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        // d(0, r) = 2 artanh(r) in the disk, d(i, ti) = ln t in the half-plane
+        let zero = Complex64::new(0.0, 0.0);
+        assert!((disk_distance(zero, Complex64::new(0.5, 0.0)) - 2.0 * 0.5_f64.atanh()).abs() < 1e-12);
+        assert!((half_plane_distance(Complex64::new(0.0, 1.0), Complex64::new(0.0, 3.0)) - 3.0_f64.ln()).abs() < 1e-12);
+
+        assert_eq!(disk_distance(zero, zero), 0.0);
+        assert!(disk_distance(zero, Complex64::new(1.0, 0.0)).is_nan());
+        assert!(half_plane_distance(Complex64::new(0.0, 1.0), Complex64::new(1.0, -1.0)).is_nan());
+    }
+
+    #[test]
+    fn test_disk_rotation_is_isometry() {
+        let rotation = MobiusTransform::rotation(0.9).unwrap();
+        let (z1, z2) = (Complex64::new(0.3, 0.1), Complex64::new(-0.2, 0.6));
+        let before = disk_distance(z1, z2);
+        let after = disk_distance(rotation.apply(z1), rotation.apply(z2));
+        assert!((before - after).abs() < 1e-12);
+
+        assert!(rotation.is_isometry(Model::Disk, 1e-10));
+    }
+
+    #[test]
+    fn test_is_isometry() {
+        // z ↦ (z - p) / (1 - p̄z) preserves the disk
+        let p = Complex64::new(0.3, -0.4);
+        let one = Complex64::new(1.0, 0.0);
+        let disk_automorphism = MobiusTransform::new(one, -p, -p.conj(), one).unwrap();
+        assert!(disk_automorphism.is_isometry(Model::Disk, 1e-10));
+        assert!(!disk_automorphism.is_isometry(Model::HalfPlane, 1e-10));
+
+        // Real coefficients with positive determinant preserve the half-plane
+        let real = MobiusTransform::new(
+            Complex64::new(2.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        assert!(real.is_isometry(Model::HalfPlane, 1e-10));
+        assert!(!real.is_isometry(Model::Disk, 1e-10));
+
+        // Euclidean scaling is not a disk isometry
+        let scaling = MobiusTransform::from_affine(Complex64::new(0.5, 0.0), Complex64::new(0.0, 0.0)).unwrap();
+        assert!(!scaling.is_isometry(Model::Disk, 1e-10));
+        assert!(scaling.is_isometry(Model::HalfPlane, 1e-10));
+    }
+
+    #[test]
+    fn test_model_conversions() {
+        let to_half_plane = MobiusTransform::disk_to_half_plane();
+        let to_disk = MobiusTransform::half_plane_to_disk();
+        assert!(to_half_plane.compose(&to_disk).approx_eq(&MobiusTransform::identity()));
+        assert!((to_half_plane.apply(Complex64::new(0.0, 0.0)) - Complex64::new(0.0, 1.0)).norm() < 1e-12);
+
+        // The unit circle maps to the real axis (1 maps to infinity)
+        for k in 1..12 {
+            let z = Complex64::from_polar(1.0, k as f64 * std::f64::consts::PI / 6.0);
+            let w = to_half_plane.apply(z);
+            assert!(w.im.abs() < 1e-12, "{} ↦ {}", z, w);
+            assert!((to_disk.apply(Complex64::new(w.re, 0.0)).norm() - 1.0).abs() < 1e-12);
+        }
+
+        // The interiors correspond, with hyperbolic distances preserved
+        let points = Model::Disk.sample_points();
+        for z in points {
+            assert!(Model::HalfPlane.contains(to_half_plane.apply(z)));
+        }
+        assert!(to_half_plane.apply(Complex64::new(0.5, 2.0)).im < 0.0);
+        for w in Model::HalfPlane.sample_points() {
+            assert!(Model::Disk.contains(to_disk.apply(w)));
+        }
+        let (z1, z2) = (points[1], points[2]);
+        let before = disk_distance(z1, z2);
+        let after = half_plane_distance(to_half_plane.apply(z1), to_half_plane.apply(z2));
+        assert!((before - after).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_project_to_real() {
+        let real = MobiusTransform::new(
+            Complex64::new(2.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+
+        // Scaled by a complex factor and slightly perturbed
+        let factor = Complex64::from_polar(3.0, 0.3);
+        let epsilon = Complex64::new(0.0, 1e-7);
+        let perturbed = MobiusTransform::new(
+            Complex64::new(2.0, 0.0) * factor + epsilon,
+            Complex64::new(1.0, 0.0) * factor,
+            Complex64::new(1.0, 0.0) * factor - epsilon,
+            Complex64::new(1.0, 0.0) * factor,
+        ).unwrap();
+        assert!(!perturbed.is_isometry(Model::HalfPlane, 1e-10));
+
+        let projected = perturbed.project_to_real().unwrap();
+        assert!(projected.to_matrix().iter().all(|z| z.im == 0.0));
+        assert!(projected.approx_eq_with_tolerance(&real, 1e-6));
+        assert!(projected.is_isometry(Model::HalfPlane, 1e-10));
+
+        // Far from real: the real parts (after fixing the phase) are singular
+        let one = Complex64::new(1.0, 0.0);
+        let i = Complex64::new(0.0, 1.0);
+        let complex = MobiusTransform::new(one, one, i, 2.0 * i).unwrap();
+        assert_eq!(complex.project_to_real(), Err(TransformError::SingularTransform));
+    }
+}
@@ -5,7 +5,35 @@
 //! where a, b, c, d are complex numbers and ad - bc ≠ 0.
 
 mod transforms;
+mod anti_mobius;
+mod builder;
+mod circles;
+mod classification;
+mod sphere;
+mod fitting;
+mod sl2;
 pub mod plane_functions;
+pub mod coloring;
+pub mod render;
+pub mod svg;
+pub mod hyperbolic;
 pub mod complex_utils;
+pub mod groups;
 
-pub use transforms::{MobiusTransform, TransformError};
+#[cfg(feature = "proptest")]
+mod arbitrary;
+
+#[cfg(feature = "test-util")]
+pub mod test_util;
+
+#[cfg(feature = "config")]
+pub mod config;
+
+#[cfg(feature = "rational")]
+pub mod rational;
+
+pub use transforms::{AtInfinity, MobiusTransform, TransformError, DEFAULT_TOLERANCE};
+pub use anti_mobius::AntiMobiusTransform;
+pub use builder::MobiusBuilder;
+pub use circles::GeneralizedCircle;
+pub use classification::{class_histogram, classify_many, TransformClass};
@@ -5,10 +5,89 @@
 
 use num_complex::Complex64;
 use crate::complex_utils::is_infinity;
+use crate::transforms::MobiusTransform;
+
+/// The grid a point was found to lie on by [`classify_grid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GridHit {
+    /// The point is on a vertical grid line (see [`vertical_grid`]).
+    Vertical,
+    /// The point is on a horizontal grid line (see [`horizontal_grid`]).
+    Horizontal,
+    /// The point is on a radial grid circle (see [`radial_grid`]).
+    Radial,
+    /// The point is on an angular grid line (see [`angular_grid`]).
+    Angular,
+    /// The point is not on any grid line.
+    None,
+}
+
+/// Periods and thicknesses for each of the grids tested by [`classify_grid`].
+///
+/// The default matches the grids drawn by the `visualize` example.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridConfig {
+    /// Spacing between vertical grid lines
+    pub vertical_period: f64,
+    /// Half-width of each vertical grid line
+    pub vertical_thickness: f64,
+    /// Spacing between horizontal grid lines
+    pub horizontal_period: f64,
+    /// Half-width of each horizontal grid line
+    pub horizontal_thickness: f64,
+    /// Spacing between radial grid circles
+    pub radial_period: f64,
+    /// Half-width of each radial grid circle
+    pub radial_thickness: f64,
+    /// Angular spacing between angular grid lines (in radians)
+    pub angular_period: f64,
+    /// Angular half-width of each angular grid line (in radians)
+    pub angular_thickness: f64,
+}
+
+impl Default for GridConfig {
+    fn default() -> Self {
+        Self {
+            vertical_period: 0.2,
+            vertical_thickness: 0.01,
+            horizontal_period: 0.2,
+            horizontal_thickness: 0.01,
+            radial_period: 0.2,
+            radial_thickness: 0.01,
+            angular_period: std::f64::consts::PI / 12.0,
+            angular_thickness: 0.01,
+        }
+    }
+}
+
+/// Determine which grid a point falls on.
+///
+/// The grids are tested in priority order: vertical, horizontal, radial, then angular.
+/// The first grid the point lies on is returned, so a point at the intersection of
+/// several grid lines reports the highest-priority one. The point at infinity lies on
+/// the vertical grid (see [`vertical_grid`]), so this returns `GridHit::Vertical` for it.
+///
+/// # Arguments
+/// * `z` - The complex number to test
+/// * `config` - The periods and thicknesses of each grid
+pub fn classify_grid(z: Complex64, config: &GridConfig) -> GridHit {
+    if vertical_grid(z, config.vertical_period, config.vertical_thickness) {
+        GridHit::Vertical
+    } else if horizontal_grid(z, config.horizontal_period, config.horizontal_thickness) {
+        GridHit::Horizontal
+    } else if radial_grid(z, config.radial_period, config.radial_thickness) {
+        GridHit::Radial
+    } else if angular_grid(z, config.angular_period, config.angular_thickness) {
+        GridHit::Angular
+    } else {
+        GridHit::None
+    }
+}
 
 /// Test if a point falls on vertical grid lines.
 ///
-/// Returns true for points at regular intervals along the real axis.
+/// Returns true for points within `thickness` of the lines x = k·period, k an integer.
+/// The lines include the imaginary axis and are symmetric about it.
 /// Vertical lines extend to infinity, so this returns true for the point at infinity.
 ///
 /// # Arguments
@@ -16,18 +95,52 @@ use crate::complex_utils::is_infinity;
 /// * `period` - The spacing between grid lines
 /// * `thickness` - The half-width of each grid line
 pub fn vertical_grid(z: Complex64, period: f64, thickness: f64) -> bool {
+    vertical_grid_with_offset(z, period, thickness, 0.0)
+}
+
+/// Test if a point falls on vertical grid lines shifted by `offset`.
+///
+/// Like [`vertical_grid`], with the lines at x = offset + k·period for every integer k,
+/// so the whole lattice moves right by `offset` and [`vertical_grid`] is the offset 0.
+///
+/// # Arguments
+/// * `z` - The complex number to test
+/// * `period` - The spacing between grid lines
+/// * `thickness` - The half-width of each grid line
+/// * `offset` - The real part of one of the grid lines
+pub fn vertical_grid_with_offset(z: Complex64, period: f64, thickness: f64, offset: f64) -> bool {
     if is_infinity(z) {
         return true; // Vertical lines pass through infinity
     }
-    
-    let re_mod = (z.re.abs() % period).abs();
-    let half_period = period / 2.0;
-    re_mod >= half_period - thickness && re_mod < half_period + thickness
+
+    near_multiple(z.re - offset, period, thickness)
+}
+
+/// Test if a point falls on vertical grid lines of constant width on screen.
+///
+/// `transform` maps the plane to the screen. Near `z` it scales lengths by |f'(z)|,
+/// so a line of half-width `screen_thickness` / |f'(z)| in the plane appears with
+/// half-width `screen_thickness` on screen. Using a fixed plane thickness instead
+/// makes lines thicken where the map expands and vanish where it contracts.
+///
+/// When rendering with [`render_to_buffer`](crate::render::render_to_buffer), which
+/// colors each pixel by the image of the pixel under a transform g, the plane-to-screen
+/// map is g⁻¹.
+///
+/// # Arguments
+/// * `z` - The complex number to test
+/// * `period` - The spacing between grid lines
+/// * `screen_thickness` - The half-width of each grid line after the transform
+/// * `transform` - The map from the plane to the screen
+pub fn vertical_grid_screen(z: Complex64, period: f64, screen_thickness: f64, transform: &MobiusTransform) -> bool {
+    let scale = transform.derivative(z).norm();
+    vertical_grid(z, period, screen_thickness / scale)
 }
 
 /// Test if a point falls on horizontal grid lines.
 ///
-/// Returns true for points at regular intervals along the imaginary axis.
+/// Returns true for points within `thickness` of the lines y = k·period, k an integer.
+/// The lines include the real axis and are symmetric about it.
 /// Horizontal lines extend to infinity, so this returns true for the point at infinity.
 ///
 /// # Arguments
@@ -35,18 +148,53 @@ pub fn vertical_grid(z: Complex64, period: f64, thickness: f64) -> bool {
 /// * `period` - The spacing between grid lines
 /// * `thickness` - The half-width of each grid line
 pub fn horizontal_grid(z: Complex64, period: f64, thickness: f64) -> bool {
+    horizontal_grid_with_offset(z, period, thickness, 0.0)
+}
+
+/// Test if a point falls on horizontal grid lines shifted by `offset`.
+///
+/// Like [`horizontal_grid`], with the lines at y = offset + k·period for every
+/// integer k, so the whole lattice moves up by `offset` and [`horizontal_grid`] is the
+/// offset 0.
+///
+/// # Arguments
+/// * `z` - The complex number to test
+/// * `period` - The spacing between grid lines
+/// * `thickness` - The half-width of each grid line
+/// * `offset` - The imaginary part of one of the grid lines
+pub fn horizontal_grid_with_offset(z: Complex64, period: f64, thickness: f64, offset: f64) -> bool {
     if is_infinity(z) {
         return true; // Horizontal lines pass through infinity
     }
-    
-    let im_mod = (z.im.abs() % period).abs();
-    let half_period = period / 2.0;
-    im_mod >= half_period - thickness && im_mod < half_period + thickness
+
+    near_multiple(z.im - offset, period, thickness)
+}
+
+/// Tests whether `x` is within `thickness` of an integer multiple of `period`.
+///
+/// Uses the Euclidean remainder, so the test is symmetric under x ↦ -x.
+fn near_multiple(x: f64, period: f64, thickness: f64) -> bool {
+    distance_to_multiple(x, period) < thickness
+}
+
+/// Returns the distance from `x` to the nearest integer multiple of `period`.
+///
+/// A period that is not positive and finite gives no grid, as in `svg::lattice`, and
+/// every point is at infinite distance from it.
+fn distance_to_multiple(x: f64, period: f64) -> f64 {
+    if !(period > 0.0 && period.is_finite()) {
+        return f64::INFINITY;
+    }
+    let remainder = x.rem_euclid(period);
+    remainder.min(period - remainder)
 }
 
 /// Test if a point falls on radial grid circles.
 ///
-/// Returns true for points at regular intervals of magnitude from the origin.
+/// Returns true for points within `thickness` of the circles about the origin with
+/// radii (k + 1/2)·period, k a non-negative integer. The half-period phase keeps a
+/// degenerate circle of radius 0 out of the grid; see [`radial_grid_with_offset`]
+/// to choose another phase.
 /// Circles do not reach infinity (they are always at finite distance from the origin),
 /// so this returns false for the point at infinity.
 ///
@@ -55,14 +203,27 @@ pub fn horizontal_grid(z: Complex64, period: f64, thickness: f64) -> bool {
 /// * `period` - The spacing between circles
 /// * `thickness` - The half-width of each circle line
 pub fn radial_grid(z: Complex64, period: f64, thickness: f64) -> bool {
+    radial_grid_with_offset(z, period, thickness, period / 2.0)
+}
+
+/// Test if a point falls on radial grid circles shifted by `offset`.
+///
+/// Like [`radial_grid`], with the circles at radii offset + k·period for every integer
+/// k giving a positive radius. An offset of period / 2 gives [`radial_grid`], and an
+/// offset of 0 puts circles at the multiples of the period, with the origin itself
+/// lying on the grid.
+///
+/// # Arguments
+/// * `z` - The complex number to test
+/// * `period` - The spacing between circles
+/// * `thickness` - The half-width of each circle line
+/// * `offset` - The radius of one of the circles
+pub fn radial_grid_with_offset(z: Complex64, period: f64, thickness: f64, offset: f64) -> bool {
     if is_infinity(z) {
         return false; // Circles don't reach infinity
     }
-    
-    let magnitude = z.norm();
-    let mag_mod = magnitude % period;
-    let half_period = period / 2.0;
-    mag_mod >= half_period - thickness && mag_mod < half_period + thickness
+
+    near_multiple(z.norm() - offset, period, thickness)
 }
 
 /// Test if a point falls on angular grid lines.
@@ -86,6 +247,143 @@ pub fn angular_grid(z: Complex64, period: f64, thickness: f64) -> bool {
     angle_mod >= half_period - thickness && angle_mod < half_period + thickness
 }
 
+/// Returns the distance from a point to the nearest vertical grid line.
+///
+/// The lines are those of [`vertical_grid`], so `vertical_grid(z, period, thickness)`
+/// holds exactly when the distance is below `thickness`, and
+/// `distance - thickness` is a signed distance to the edge of the thick line,
+/// negative inside it. A smoothstep of that signed distance over about a pixel gives
+/// anti-aliased lines. The point at infinity lies on every vertical line, at distance 0.
+pub fn vertical_grid_distance(z: Complex64, period: f64) -> f64 {
+    if is_infinity(z) {
+        return 0.0;
+    }
+    distance_to_multiple(z.re, period)
+}
+
+/// Returns the distance from a point to the nearest horizontal grid line.
+///
+/// The counterpart of [`vertical_grid_distance`] for the lines of [`horizontal_grid`].
+/// The point at infinity lies on every horizontal line, at distance 0.
+pub fn horizontal_grid_distance(z: Complex64, period: f64) -> f64 {
+    if is_infinity(z) {
+        return 0.0;
+    }
+    distance_to_multiple(z.im, period)
+}
+
+/// Returns the distance from a point to the nearest radial grid circle.
+///
+/// The circles are those of [`radial_grid`], at radii (k + 1/2)·period, and the
+/// distance is measured radially, which for circles is the Euclidean distance. No
+/// circle reaches infinity, so the point at infinity is at infinite distance.
+pub fn radial_grid_distance(z: Complex64, period: f64) -> f64 {
+    if is_infinity(z) {
+        return f64::INFINITY;
+    }
+    distance_to_multiple(z.norm() - period / 2.0, period)
+}
+
+/// Returns the angular distance, in radians, from a point to the nearest angular grid line.
+///
+/// The rays are those of [`angular_grid`], at angles (k + 1/2)·period in [0, 2π), and
+/// the distance is compared against its angular thickness. The Euclidean distance to
+/// the ray is about |z| times this. Every ray passes through infinity, at distance 0.
+pub fn angular_grid_distance(z: Complex64, period: f64) -> f64 {
+    if is_infinity(z) {
+        return 0.0;
+    }
+    let angle = z.arg().rem_euclid(2.0 * std::f64::consts::PI);
+    distance_to_multiple(angle - period / 2.0, period)
+}
+
+/// Test if a point falls on a log-polar grid line.
+///
+/// The grid has circles where ln|z| is an integer multiple of `radial_period`
+/// (so the unit circle is always a grid line) and rays where arg(z) is an integer
+/// multiple of `angular_period` (so the positive real axis is always a grid line).
+/// Circles are equally spaced in log-magnitude, which makes the grid invariant
+/// under z ↦ 1/z when the angular period divides 2π.
+///
+/// ln|z| diverges at 0, so this returns false at 0. By the z ↦ 1/z symmetry the
+/// point at infinity is treated the same way, so this also returns false for it.
+///
+/// # Arguments
+/// * `z` - The complex number to test
+/// * `radial_period` - The spacing between circles in ln|z|
+/// * `angular_period` - The angular spacing between rays (in radians)
+/// * `thickness` - The half-width of each line, in ln|z| for circles and radians for rays
+pub fn log_polar_grid(z: Complex64, radial_period: f64, angular_period: f64, thickness: f64) -> bool {
+    if is_infinity(z) {
+        return false; // Mirrors the behavior at 0 under z ↦ 1/z
+    }
+
+    let magnitude = z.norm();
+    if magnitude == 0.0 {
+        return false; // ln|z| diverges at the origin
+    }
+
+    let log_mod = magnitude.ln().rem_euclid(radial_period);
+    let on_circle = log_mod.min(radial_period - log_mod) < thickness;
+
+    let angle_mod = z.arg().rem_euclid(angular_period);
+    let on_ray = angle_mod.min(angular_period - angle_mod) < thickness;
+
+    on_circle || on_ray
+}
+
+/// Test if a point falls on a "filled" square of a checkerboard pattern.
+///
+/// The plane is tiled by squares of side `period` with corners at integer multiples
+/// of `period`. A point is on a filled square when floor(re/period) + floor(im/period)
+/// is even, so the square [0, period) × [0, period) is filled. Each square contains
+/// its lower and left edges, so points on a shared edge belong to the square
+/// above or to the right of it.
+/// The point at infinity is not in any square, so this returns false for it.
+///
+/// # Arguments
+/// * `z` - The complex number to test
+/// * `period` - The side length of each square
+pub fn checkerboard(z: Complex64, period: f64) -> bool {
+    if is_infinity(z) {
+        return false; // Infinity belongs to no square
+    }
+
+    let cell_sum = (z.re / period).floor() + (z.im / period).floor();
+    cell_sum.rem_euclid(2.0) == 0.0
+}
+
+/// Test if a point lies inside the open unit disk |z| < 1.
+///
+/// Points on the unit circle are on the boundary and are not inside.
+/// The point at infinity lies outside the disk, so this returns false for it.
+///
+/// # Arguments
+/// * `z` - The complex number to test
+pub fn in_unit_disk(z: Complex64) -> bool {
+    if is_infinity(z) {
+        return false; // Infinity is outside the disk
+    }
+
+    z.norm_sqr() < 1.0
+}
+
+/// Test if a point lies in the open upper half-plane Im(z) > 0.
+///
+/// Points on the real axis are on the boundary and are not inside.
+/// The point at infinity lies on the boundary of the half-plane (the real axis
+/// closes up through infinity), so this returns false for it.
+///
+/// # Arguments
+/// * `z` - The complex number to test
+pub fn in_upper_half_plane(z: Complex64) -> bool {
+    if is_infinity(z) {
+        return false; // Infinity is on the boundary
+    }
+
+    z.im > 0.0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,11 +393,11 @@ mod tests {
     #[test]
     fn test_vertical_grid() {
         // Test point on vertical grid line
-        let z = Complex64::new(0.5, 1.0);
+        let z = Complex64::new(0.6, 1.0);
         assert!(vertical_grid(z, 0.2, 0.01));
         
         // Test point off vertical grid line
-        let z = Complex64::new(0.45, 1.0);
+        let z = Complex64::new(0.5, 1.0);
         assert!(!vertical_grid(z, 0.2, 0.01));
         
         // Test infinity (vertical lines pass through infinity)
@@ -109,12 +407,16 @@ mod tests {
     #[test]
     fn test_horizontal_grid() {
         // Test point on horizontal grid line
-        let z = Complex64::new(1.0, 0.5);
+        let z = Complex64::new(1.0, 0.6);
         assert!(horizontal_grid(z, 0.2, 0.01));
         
         // Test point off horizontal grid line
-        let z = Complex64::new(1.0, 0.45);
+        let z = Complex64::new(1.0, 0.5);
         assert!(!horizontal_grid(z, 0.2, 0.01));
+
+        // The real axis is a grid line, seen from both sides
+        assert!(horizontal_grid(Complex64::new(1.0, -0.005), 0.2, 0.01));
+        assert!(horizontal_grid(Complex64::new(1.0, 0.005), 0.2, 0.01));
         
         // Test infinity (horizontal lines pass through infinity)
         assert!(horizontal_grid(COMPLEX_INFINITY, 0.2, 0.01));
@@ -155,4 +457,255 @@ mod tests {
         // Test infinity (all angular rays pass through infinity)
         assert!(angular_grid(COMPLEX_INFINITY, period, thickness));
     }
+
+    #[test]
+    fn test_in_unit_disk() {
+        assert!(in_unit_disk(Complex64::new(0.0, 0.0)));
+        assert!(in_unit_disk(Complex64::new(0.5, -0.5)));
+
+        // Boundary points are not inside the open disk
+        assert!(!in_unit_disk(Complex64::new(1.0, 0.0)));
+        assert!(!in_unit_disk(Complex64::new(0.0, -1.0)));
+
+        assert!(!in_unit_disk(Complex64::new(1.5, 0.0)));
+
+        // Test infinity (outside the disk)
+        assert!(!in_unit_disk(COMPLEX_INFINITY));
+    }
+
+    #[test]
+    fn test_in_upper_half_plane() {
+        assert!(in_upper_half_plane(Complex64::new(-3.0, 0.1)));
+        assert!(!in_upper_half_plane(Complex64::new(2.0, -0.1)));
+
+        // Boundary points on the real axis are not inside the open half-plane
+        assert!(!in_upper_half_plane(Complex64::new(0.0, 0.0)));
+        assert!(!in_upper_half_plane(Complex64::new(5.0, 0.0)));
+
+        // Test infinity (on the boundary)
+        assert!(!in_upper_half_plane(COMPLEX_INFINITY));
+    }
+
+    #[test]
+    fn test_checkerboard() {
+        let period = 0.5;
+
+        // Cell centers alternate between filled and empty
+        assert!(checkerboard(Complex64::new(0.25, 0.25), period));
+        assert!(!checkerboard(Complex64::new(0.75, 0.25), period));
+        assert!(!checkerboard(Complex64::new(0.25, 0.75), period));
+        assert!(checkerboard(Complex64::new(0.75, 0.75), period));
+
+        // Negative coordinates continue the pattern without mirroring
+        assert!(!checkerboard(Complex64::new(-0.25, 0.25), period));
+        assert!(checkerboard(Complex64::new(-0.25, -0.25), period));
+
+        // A point on a cell boundary belongs to the cell to its right
+        assert!(!checkerboard(Complex64::new(0.5, 0.25), period));
+
+        // Test infinity (belongs to no square)
+        assert!(!checkerboard(COMPLEX_INFINITY, period));
+    }
+
+    #[test]
+    fn test_log_polar_grid() {
+        let radial_period = 0.5;
+        let angular_period = PI / 6.0;
+        let thickness = 0.01;
+
+        // Points near |z| = 1 (ln|z| = 0) are on a circle, from either side
+        let angle = angular_period / 2.0;
+        let z = Complex64::from_polar(1.0, angle);
+        assert!(log_polar_grid(z, radial_period, angular_period, thickness));
+        let z = Complex64::from_polar(1.005, angle);
+        assert!(log_polar_grid(z, radial_period, angular_period, thickness));
+        let z = Complex64::from_polar(0.995, angle);
+        assert!(log_polar_grid(z, radial_period, angular_period, thickness));
+
+        // Circles are equally spaced in ln|z|
+        let z = Complex64::from_polar(radial_period.exp(), angle);
+        assert!(log_polar_grid(z, radial_period, angular_period, thickness));
+        let z = Complex64::from_polar((-radial_period).exp(), angle);
+        assert!(log_polar_grid(z, radial_period, angular_period, thickness));
+
+        // Point on the ray at angle π/6, away from any circle
+        let z = Complex64::from_polar(1.2, PI / 6.0);
+        assert!(log_polar_grid(z, radial_period, angular_period, thickness));
+
+        // Point off both circles and rays
+        let z = Complex64::from_polar(1.2, angle);
+        assert!(!log_polar_grid(z, radial_period, angular_period, thickness));
+
+        // Test the origin and infinity (ln|z| diverges)
+        assert!(!log_polar_grid(Complex64::new(0.0, 0.0), radial_period, angular_period, thickness));
+        assert!(!log_polar_grid(COMPLEX_INFINITY, radial_period, angular_period, thickness));
+    }
+
+    #[test]
+    fn test_classify_grid() {
+        let config = GridConfig::default();
+
+        // Point on a vertical line only
+        let z = Complex64::new(0.6, 0.05);
+        assert_eq!(classify_grid(z, &config), GridHit::Vertical);
+
+        // Point on a horizontal line only
+        let z = Complex64::new(0.05, 0.6);
+        assert_eq!(classify_grid(z, &config), GridHit::Horizontal);
+
+        // Point on both a vertical and a horizontal line reports the vertical one
+        let z = Complex64::new(0.6, 0.6);
+        assert_eq!(classify_grid(z, &config), GridHit::Vertical);
+
+        // Point off every grid
+        let z = Complex64::new(0.45, 0.05);
+        assert_eq!(classify_grid(z, &config), GridHit::None);
+
+        // Test infinity (vertical lines pass through infinity)
+        assert_eq!(classify_grid(COMPLEX_INFINITY, &config), GridHit::Vertical);
+    }
+
+    #[test]
+    fn test_vertical_grid_symmetric_about_zero() {
+        // Lines at -0.2, 0 and 0.2, with the same hits on both sides
+        for x in [-0.2, 0.0, 0.2, -0.195, 0.195, -0.005, 0.005] {
+            assert!(vertical_grid(Complex64::new(x, 0.3), 0.2, 0.01), "x = {}", x);
+        }
+        for x in [-0.1, 0.1, -0.185, 0.185] {
+            assert!(!vertical_grid(Complex64::new(x, 0.3), 0.2, 0.01), "x = {}", x);
+        }
+    }
+
+    #[test]
+    fn test_grid_offset() {
+        for x in [-0.15, 0.05, 0.25] {
+            assert!(vertical_grid_with_offset(Complex64::new(x, 0.3), 0.2, 0.01, 0.05));
+            assert!(horizontal_grid_with_offset(Complex64::new(0.3, x), 0.2, 0.01, 0.05));
+        }
+        assert!(!vertical_grid_with_offset(Complex64::new(0.0, 0.3), 0.2, 0.01, 0.05));
+        assert!(!horizontal_grid_with_offset(Complex64::new(0.3, 0.0), 0.2, 0.01, 0.05));
+    }
+
+    #[test]
+    fn test_vertical_grid_screen() {
+        // z ↦ 4z magnifies the plane, so a screen half-width of 0.04 is 0.01 in the plane
+        let scaling = MobiusTransform::from_affine(Complex64::new(4.0, 0.0), Complex64::new(0.0, 0.0)).unwrap();
+        let identity = MobiusTransform::identity();
+        for x in [0.6, 0.608, 0.592] {
+            let z = Complex64::new(x, 1.0);
+            assert!(vertical_grid_screen(z, 0.2, 0.04, &scaling));
+            assert_eq!(vertical_grid_screen(z, 0.2, 0.01, &identity), vertical_grid(z, 0.2, 0.01));
+        }
+        for x in [0.615, 0.585] {
+            let z = Complex64::new(x, 1.0);
+            assert!(!vertical_grid_screen(z, 0.2, 0.04, &scaling));
+            assert!(vertical_grid_screen(z, 0.2, 0.04, &identity));
+        }
+
+        // f(z) = 1/z expands near the origin, where lines get thinner in the plane, and
+        // contracts far from it, where they get thicker
+        let inversion = MobiusTransform::new(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap();
+        assert!(vertical_grid(Complex64::new(0.208, 0.0), 0.2, 0.01));
+        assert!(!vertical_grid_screen(Complex64::new(0.208, 0.0), 0.2, 0.01, &inversion));
+        assert!(!vertical_grid(Complex64::new(10.05, 0.0), 0.2, 0.01));
+        assert!(vertical_grid_screen(Complex64::new(10.05, 0.0), 0.2, 0.01, &inversion));
+        assert!(vertical_grid_screen(COMPLEX_INFINITY, 0.2, 0.01, &inversion));
+    }
+
+    #[test]
+    fn test_grid_distances_match_boolean_grids() {
+        let (period, thickness) = (0.2, 0.01);
+        let samples = (0..400).map(|k| Complex64::from_polar(0.01 * k as f64 + 0.003, 0.137 * k as f64));
+        for z in samples {
+            // Skip points within rounding of a line edge, where the half-open boolean
+            // intervals and the distances may disagree
+            let near_edge = |distance: f64| (distance - thickness).abs() < 1e-9;
+
+            let distance = vertical_grid_distance(z, period);
+            assert!(distance >= 0.0 && distance <= period / 2.0);
+            if !near_edge(distance) {
+                assert_eq!(vertical_grid(z, period, thickness), distance < thickness, "z = {}", z);
+            }
+            let distance = horizontal_grid_distance(z, period);
+            if !near_edge(distance) {
+                assert_eq!(horizontal_grid(z, period, thickness), distance < thickness, "z = {}", z);
+            }
+            let distance = radial_grid_distance(z, period);
+            if !near_edge(distance) {
+                assert_eq!(radial_grid(z, period, thickness), distance < thickness, "z = {}", z);
+            }
+            let distance = angular_grid_distance(z, PI / 12.0);
+            if !near_edge(distance) {
+                assert_eq!(angular_grid(z, PI / 12.0, thickness), distance < thickness, "z = {}", z);
+            }
+        }
+    }
+
+    #[test]
+    fn test_grid_distance_values() {
+        // Zero on a line, growing linearly away from it
+        assert!(vertical_grid_distance(Complex64::new(0.4, 3.0), 0.2) < 1e-12);
+        assert!((vertical_grid_distance(Complex64::new(0.45, 3.0), 0.2) - 0.05).abs() < 1e-12);
+        assert!((horizontal_grid_distance(Complex64::new(3.0, -0.47), 0.2) - 0.07).abs() < 1e-12);
+        // Circles at radii 0.1, 0.3, ...
+        assert!(radial_grid_distance(Complex64::new(0.0, 0.3), 0.2) < 1e-12);
+        assert!((radial_grid_distance(Complex64::new(0.0, 0.2), 0.2) - 0.1).abs() < 1e-12);
+        // Rays at angles π/12, 3π/12, ...
+        assert!(angular_grid_distance(Complex64::from_polar(2.0, PI / 4.0), PI / 6.0) < 1e-12);
+
+        assert_eq!(vertical_grid_distance(COMPLEX_INFINITY, 0.2), 0.0);
+        assert_eq!(horizontal_grid_distance(COMPLEX_INFINITY, 0.2), 0.0);
+        assert_eq!(radial_grid_distance(COMPLEX_INFINITY, 0.2), f64::INFINITY);
+        assert_eq!(angular_grid_distance(COMPLEX_INFINITY, 0.2), 0.0);
+    }
+
+    #[test]
+    fn test_radial_grid_offset() {
+        // Circles at radii 0.05, 0.25, 0.45, ...
+        for r in [0.05, 0.25, 0.45] {
+            assert!(radial_grid_with_offset(Complex64::from_polar(r, 1.0), 0.2, 0.01, 0.05));
+            assert!(!radial_grid_with_offset(Complex64::from_polar(r + 0.1, 1.0), 0.2, 0.01, 0.05));
+        }
+        // Moving the offset moves the circle by the same amount
+        let z = Complex64::new(0.0, 0.5);
+        assert!(radial_grid(z, 0.2, 0.01));
+        assert!(!radial_grid_with_offset(z, 0.2, 0.01, 0.15));
+        assert!(radial_grid_with_offset(z + Complex64::new(0.0, 0.05), 0.2, 0.01, 0.15));
+
+        // The default phase is half a period
+        for k in 0..50 {
+            let z = Complex64::from_polar(0.0137 * k as f64, 0.3 * k as f64);
+            assert_eq!(radial_grid(z, 0.2, 0.01), radial_grid_with_offset(z, 0.2, 0.01, 0.1));
+        }
+        assert!(!radial_grid_with_offset(COMPLEX_INFINITY, 0.2, 0.01, 0.0));
+        assert!(radial_grid_with_offset(Complex64::new(0.0, 0.0), 0.2, 0.01, 0.0));
+    }
+
+    #[test]
+    fn test_grid_invalid_period() {
+        // A negative period used to put every point on the grid
+        let z = Complex64::new(0.37, 0.0);
+        for period in [-0.2, 0.0, f64::INFINITY, f64::NAN] {
+            assert!(!vertical_grid(z, period, 0.01), "period = {}", period);
+            assert!(!horizontal_grid(z * Complex64::i(), period, 0.01), "period = {}", period);
+            assert!(!radial_grid(z, period, 0.01), "period = {}", period);
+            assert_eq!(vertical_grid_distance(z, period), f64::INFINITY);
+        }
+    }
+
+    #[test]
+    fn test_radial_and_angular_grid_distance_invalid_period() {
+        let z = Complex64::new(0.3, 0.4);
+        for period in [0.0, -0.2, f64::NAN] {
+            assert_eq!(radial_grid_distance(z, period), f64::INFINITY, "period = {}", period);
+            assert_eq!(angular_grid_distance(z, period), f64::INFINITY, "period = {}", period);
+            assert!(!radial_grid(z, period, 0.01), "period = {}", period);
+            assert!(!angular_grid(z, period, 0.01), "period = {}", period);
+        }
+    }
 }
@@ -0,0 +1,223 @@
+//! Exact Möbius transformations over the Gaussian rationals.
+//!
+//! [`MobiusTransformRational`] mirrors the core operations of
+//! [`MobiusTransform`] with coefficients in Q(i), using arbitrary precision
+//! rationals so that no arithmetic is rounded and no intermediate result overflows.
+//! It is meant for checking algebraic identities exactly, e.g. in tests, where the
+//! tolerances of the floating point type would hide small errors.
+
+use num_complex::{Complex, Complex64};
+use num_rational::BigRational;
+use num_traits::{One, ToPrimitive, Zero};
+use crate::transforms::{AtInfinity, MobiusTransform, TransformError};
+
+/// A complex number with rational real and imaginary parts.
+pub type GaussianRational = Complex<BigRational>;
+
+/// A Möbius transformation f(z) = (az + b) / (cz + d) with Gaussian rational
+/// coefficients and ad - bc ≠ 0.
+///
+/// Coefficients are stored as given; like a matrix of PSL(2, C), the transformation
+/// only depends on them up to a common nonzero factor, and equality compares the
+/// coefficients projectively.
+///
+/// # Examples
+/// ```
+/// use mobius_applicatio::rational::{GaussianRational, MobiusTransformRational};
+/// use num_rational::BigRational;
+///
+/// let int = |n: i64| GaussianRational::new(BigRational::from_integer(n.into()), BigRational::from_integer(0.into()));
+/// let inversion = MobiusTransformRational::new(int(0), int(1), int(1), int(0)).unwrap();
+/// assert_eq!(inversion.compose(&inversion), MobiusTransformRational::identity());
+/// ```
+#[derive(Debug, Clone)]
+pub struct MobiusTransformRational {
+    a: GaussianRational,
+    b: GaussianRational,
+    c: GaussianRational,
+    d: GaussianRational,
+}
+
+impl MobiusTransformRational {
+    /// Creates a new exact Möbius transformation.
+    ///
+    /// # Errors
+    /// Returns `TransformError::SingularTransform` if ad - bc is exactly zero.
+    pub fn new(
+        a: GaussianRational,
+        b: GaussianRational,
+        c: GaussianRational,
+        d: GaussianRational,
+    ) -> Result<Self, TransformError> {
+        let transform = Self { a, b, c, d };
+        if transform.determinant().is_zero() {
+            return Err(TransformError::SingularTransform);
+        }
+        Ok(transform)
+    }
+
+    /// Creates the identity transformation.
+    pub fn identity() -> Self {
+        Self {
+            a: GaussianRational::one(),
+            b: GaussianRational::zero(),
+            c: GaussianRational::zero(),
+            d: GaussianRational::one(),
+        }
+    }
+
+    /// Returns the determinant ad - bc of the coefficients as given.
+    pub fn determinant(&self) -> GaussianRational {
+        &self.a * &self.d - &self.b * &self.c
+    }
+
+    /// Returns true if the transformation is exactly the identity, i.e. b = c = 0 and a = d.
+    pub fn is_identity(&self) -> bool {
+        self.b.is_zero() && self.c.is_zero() && self.a == self.d
+    }
+
+    /// Applies the transformation to a finite point.
+    ///
+    /// Returns `Err(AtInfinity)` when z is the pole -d/c, as
+    /// [`MobiusTransform::try_apply`] does.
+    pub fn apply(&self, z: &GaussianRational) -> Result<GaussianRational, AtInfinity> {
+        let denominator = &self.c * z + &self.d;
+        if denominator.is_zero() {
+            return Err(AtInfinity);
+        }
+        Ok((&self.a * z + &self.b) / denominator)
+    }
+
+    /// Returns a/c, the image of the point at infinity, or `Err(AtInfinity)` when
+    /// c = 0 and infinity is fixed.
+    pub fn image_of_infinity(&self) -> Result<GaussianRational, AtInfinity> {
+        if self.c.is_zero() {
+            return Err(AtInfinity);
+        }
+        Ok(&self.a / &self.c)
+    }
+
+    /// Composes this transformation with another: (self ∘ other)(z) = self(other(z))
+    ///
+    /// The coefficients are the exact matrix product, so the determinant of the result
+    /// is the product of the determinants and is never zero.
+    pub fn compose(&self, other: &MobiusTransformRational) -> MobiusTransformRational {
+        Self {
+            a: &self.a * &other.a + &self.b * &other.c,
+            b: &self.a * &other.b + &self.b * &other.d,
+            c: &self.c * &other.a + &self.d * &other.c,
+            d: &self.c * &other.b + &self.d * &other.d,
+        }
+    }
+
+    /// Returns the inverse transformation, with the adjugate coefficients
+    /// [[d, -b], [-c, a]].
+    pub fn inverse(&self) -> MobiusTransformRational {
+        Self {
+            a: self.d.clone(),
+            b: -self.b.clone(),
+            c: -self.c.clone(),
+            d: self.a.clone(),
+        }
+    }
+
+    /// Rounds the coefficients to the nearest `f64` values.
+    ///
+    /// The coefficients are first divided by the one of largest magnitude, exactly, so
+    /// that transformations with huge numerators and denominators still convert.
+    ///
+    /// # Errors
+    /// Returns `TransformError::SingularTransform` if the rounded coefficients are
+    /// singular within [`DEFAULT_TOLERANCE`](crate::DEFAULT_TOLERANCE).
+    pub fn to_float(&self) -> Result<MobiusTransform, TransformError> {
+        let coefficients = [&self.a, &self.b, &self.c, &self.d];
+        let largest = coefficients
+            .iter()
+            .max_by(|x, y| x.norm_sqr().cmp(&y.norm_sqr()))
+            .map(|x| (*x).clone())
+            .unwrap_or_else(GaussianRational::one);
+        let [a, b, c, d] = coefficients.map(|x| to_complex64(&(x / &largest)));
+        MobiusTransform::new(a, b, c, d)
+    }
+}
+
+/// Equality of transformations: the coefficient vectors are proportional.
+impl PartialEq for MobiusTransformRational {
+    fn eq(&self, other: &Self) -> bool {
+        let (u, v) = ([&self.a, &self.b, &self.c, &self.d], [&other.a, &other.b, &other.c, &other.d]);
+        (0..4).all(|i| (i + 1..4).all(|j| u[i] * v[j] == u[j] * v[i]))
+    }
+}
+
+impl Eq for MobiusTransformRational {}
+
+/// Rounds a Gaussian rational to the nearest complex float, NaN if it does not fit.
+fn to_complex64(z: &GaussianRational) -> Complex64 {
+    Complex64::new(z.re.to_f64().unwrap_or(f64::NAN), z.im.to_f64().unwrap_or(f64::NAN))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gaussian(re: i64, im: i64) -> GaussianRational {
+        GaussianRational::new(BigRational::from_integer(re.into()), BigRational::from_integer(im.into()))
+    }
+
+    fn fraction(numerator: i64, denominator: i64) -> GaussianRational {
+        GaussianRational::new(
+            BigRational::new(numerator.into(), denominator.into()),
+            BigRational::zero(),
+        )
+    }
+
+    #[test]
+    fn test_inversion_squared_is_exactly_identity() {
+        let inversion = MobiusTransformRational::new(gaussian(0, 0), gaussian(1, 0), gaussian(1, 0), gaussian(0, 0)).unwrap();
+        let square = inversion.compose(&inversion);
+        assert!(square.is_identity());
+        assert_eq!(square, MobiusTransformRational::identity());
+        assert_ne!(inversion, MobiusTransformRational::identity());
+    }
+
+    #[test]
+    fn test_exact_apply_compose_inverse() {
+        // f(z) = (z + i) / (2z - 1/3), g(z) = 3z + 1 - i
+        let f = MobiusTransformRational::new(gaussian(1, 0), gaussian(0, 1), gaussian(2, 0), -fraction(1, 3)).unwrap();
+        let g = MobiusTransformRational::new(gaussian(3, 0), gaussian(1, -1), gaussian(0, 0), gaussian(1, 0)).unwrap();
+
+        let z = gaussian(2, 1) * fraction(1, 7);
+        let fg = f.compose(&g);
+        assert_eq!(fg.apply(&z).unwrap(), f.apply(&g.apply(&z).unwrap()).unwrap());
+        assert_eq!(f.inverse().apply(&f.apply(&z).unwrap()).unwrap(), z);
+        assert!(f.inverse().compose(&f).is_identity());
+        assert_eq!(fg.inverse(), g.inverse().compose(&f.inverse()));
+        // ((2 + 8i)/7) / ((5 + 6i)/21) = (6 + 24i) / (5 + 6i)
+        assert_eq!(f.apply(&z).unwrap(), gaussian(6, 24) / gaussian(5, 6));
+    }
+
+    #[test]
+    fn test_pole_and_infinity() {
+        let f = MobiusTransformRational::new(gaussian(1, 0), gaussian(0, 1), gaussian(2, 0), -fraction(1, 3)).unwrap();
+        assert_eq!(f.apply(&fraction(1, 6)), Err(AtInfinity));
+        assert_eq!(f.image_of_infinity().unwrap(), fraction(1, 2));
+        assert_eq!(MobiusTransformRational::identity().image_of_infinity(), Err(AtInfinity));
+    }
+
+    #[test]
+    fn test_singular_and_to_float() {
+        assert_eq!(
+            MobiusTransformRational::new(gaussian(1, 0), gaussian(2, 0), fraction(1, 2), gaussian(1, 0)).unwrap_err(),
+            TransformError::SingularTransform
+        );
+
+        let f = MobiusTransformRational::new(gaussian(1, 0), gaussian(0, 1), gaussian(2, 0), -fraction(1, 3)).unwrap();
+        let expected = MobiusTransform::new(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 1.0),
+            Complex64::new(2.0, 0.0),
+            Complex64::new(-1.0 / 3.0, 0.0),
+        ).unwrap();
+        assert!(f.to_float().unwrap().approx_eq(&expected));
+    }
+}
@@ -0,0 +1,264 @@
+//! Headless rendering of transformed planes into pixel buffers.
+//!
+//! Produces plain RGBA buffers, so renders can be written to image files or
+//! compared in tests without a window.
+
+use num_complex::Complex64;
+use crate::transforms::MobiusTransform;
+
+/// Number of bytes per RGBA pixel.
+const BYTES_PER_PIXEL: usize = 4;
+
+/// Renders the square [-range, range] × [-range, range] of the complex plane, as seen
+/// through `transform`, into a row-major RGBA buffer of `width` × `height` pixels.
+///
+/// Each pixel samples the plane at its top-left corner: column `col` and row `row`
+/// map to z = (-range + 2·range·col/width) + i(range - 2·range·row/height), so the
+/// imaginary axis points up. The pixel is colored by `color_fn(transform.apply(z))`.
+///
+/// The returned buffer has `width * height * 4` bytes.
+///
+/// # Examples
+/// ```
+/// use mobius_applicatio::MobiusTransform;
+/// use mobius_applicatio::render::render_to_buffer;
+///
+/// let buffer = render_to_buffer(&MobiusTransform::identity(), 8, 8, 2.0, |_| [255, 0, 0, 255]);
+/// assert_eq!(buffer.len(), 8 * 8 * 4);
+/// ```
+pub fn render_to_buffer(
+    transform: &MobiusTransform,
+    width: usize,
+    height: usize,
+    range: f64,
+    color_fn: impl Fn(Complex64) -> [u8; 4],
+) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(width * height * BYTES_PER_PIXEL);
+
+    let bounds = (-range, range, -range, range);
+    for row in 0..height {
+        for col in 0..width {
+            let z = transform.apply(pixel_to_complex(col, row, width, height, bounds));
+            buffer.extend_from_slice(&color_fn(z));
+        }
+    }
+
+    buffer
+}
+
+/// Renders like [`render_to_buffer`], averaging `samples_per_pixel` × `samples_per_pixel`
+/// samples in each pixel to smooth the edges of hard-edged colorings such as grids.
+///
+/// Pixel (col, row) is split into an N × N block of subpixels, each sampled at its
+/// top-left corner as in [`render_to_buffer`], so N = 1 (or 0, which is treated as 1)
+/// gives exactly the same buffer. Colors are averaged with alpha weighting: the alpha
+/// is the mean alpha, and the color channels are the alpha-weighted mean, so fully
+/// transparent samples lower the coverage without darkening the color. Channels are
+/// rounded to the nearest integer.
+///
+/// The cost is N² calls to `transform.apply` and `color_fn` per pixel.
+pub fn render_supersampled(
+    transform: &MobiusTransform,
+    width: usize,
+    height: usize,
+    range: f64,
+    samples_per_pixel: usize,
+    color_fn: impl Fn(Complex64) -> [u8; 4],
+) -> Vec<u8> {
+    let n = samples_per_pixel.max(1);
+    let mut buffer = Vec::with_capacity(width * height * BYTES_PER_PIXEL);
+
+    let bounds = (-range, range, -range, range);
+    for row in 0..height {
+        for col in 0..width {
+            // Sums of alpha and of alpha-weighted color channels
+            let mut alpha_sum = 0.0;
+            let mut color_sum = [0.0; 3];
+            for sub_row in 0..n {
+                for sub_col in 0..n {
+                    let z = pixel_to_complex(col * n + sub_col, row * n + sub_row, width * n, height * n, bounds);
+                    let [r, g, b, a] = color_fn(transform.apply(z));
+                    let alpha = f64::from(a);
+                    alpha_sum += alpha;
+                    for (sum, channel) in color_sum.iter_mut().zip([r, g, b]) {
+                        *sum += alpha * f64::from(channel);
+                    }
+                }
+            }
+            for sum in color_sum {
+                let channel = if alpha_sum > 0.0 { sum / alpha_sum } else { 0.0 };
+                buffer.push(channel.round() as u8);
+            }
+            buffer.push((alpha_sum / (n * n) as f64).round() as u8);
+        }
+    }
+
+    buffer
+}
+
+/// Returns the point of the plane at the top-left corner of a pixel.
+///
+/// `bounds` is the viewport (x_min, x_max, y_min, y_max), as returned by
+/// [`fit_viewport`]. Column `col` and row `row` map to
+/// z = (x_min + (x_max - x_min)·col/width) + i(y_max - (y_max - y_min)·row/height), so
+/// the imaginary axis points up. This is the sample used by [`render_to_buffer`];
+/// add half a pixel to `col` and `row` for the pixel center.
+pub fn pixel_to_complex(col: usize, row: usize, width: usize, height: usize, bounds: (f64, f64, f64, f64)) -> Complex64 {
+    let (x_min, x_max, y_min, y_max) = bounds;
+    let real = x_min + (col as f64 / width as f64) * (x_max - x_min);
+    // Screen rows go down, the imaginary axis goes up
+    let imag = y_max - (row as f64 / height as f64) * (y_max - y_min);
+    Complex64::new(real, imag)
+}
+
+/// Returns the (col, row) of the pixel containing a point, the inverse of
+/// [`pixel_to_complex`].
+///
+/// Each pixel covers the half-open cell extending right and down from its top-left
+/// corner. Returns `None` for the point at infinity, NaN, and points outside the
+/// viewport.
+pub fn complex_to_pixel(z: Complex64, width: usize, height: usize, bounds: (f64, f64, f64, f64)) -> Option<(usize, usize)> {
+    let (x_min, x_max, y_min, y_max) = bounds;
+    let col = ((z.re - x_min) / (x_max - x_min) * width as f64).floor();
+    let row = ((y_max - z.im) / (y_max - y_min) * height as f64).floor();
+    // Also rejects NaN and infinite coordinates
+    if !(col >= 0.0 && col < width as f64 && row >= 0.0 && row < height as f64) {
+        return None;
+    }
+    Some((col as usize, row as usize))
+}
+
+/// Returns the bounding box (x_min, x_max, y_min, y_max) of the images of the sample
+/// points under `transform`.
+///
+/// Images at infinity (and NaN) are ignored, so samples near the pole do not blow up
+/// the box, though their large finite images still count. If no image is finite the
+/// empty box (∞, -∞, ∞, -∞) is returned.
+pub fn fit_viewport(transform: &MobiusTransform, sample_points: &[Complex64]) -> (f64, f64, f64, f64) {
+    transform
+        .apply_iter(sample_points.iter().copied())
+        .filter(|w| w.is_finite())
+        .fold(
+            (f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY, f64::NEG_INFINITY),
+            |(x_min, x_max, y_min, y_max), w| {
+                (x_min.min(w.re), x_max.max(w.re), y_min.min(w.im), y_max.max(w.im))
+            },
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes the sign of each coordinate in the pixel, for locating samples.
+    fn quadrant_color(z: Complex64) -> [u8; 4] {
+        [u8::from(z.re >= 0.0), u8::from(z.im >= 0.0), 0, 255]
+    }
+
+    fn pixel(buffer: &[u8], width: usize, row: usize, col: usize) -> [u8; 4] {
+        let start = (row * width + col) * BYTES_PER_PIXEL;
+        buffer[start..start + BYTES_PER_PIXEL].try_into().unwrap()
+    }
+
+    #[test]
+    fn test_render_supersampled_smooths_edges() {
+        // Opaque red left of the imaginary axis, transparent to the right
+        let half_plane = |z: Complex64| if z.re < 0.0 { [255, 0, 0, 255] } else { [0, 0, 0, 0] };
+        let identity = MobiusTransform::identity();
+
+        // With width 3 and range 1.5 the middle column covers [-0.5, 0.5), straddling
+        // the edge; its single sample at -0.5 is fully inside
+        let single = render_supersampled(&identity, 3, 1, 1.5, 1, half_plane);
+        assert_eq!(single, render_to_buffer(&identity, 3, 1, 1.5, half_plane));
+        assert_eq!(pixel(&single, 3, 0, 1), [255, 0, 0, 255]);
+
+        // 2 × 2 subsamples at -0.5 and 0 give half coverage, without darkening
+        let multi = render_supersampled(&identity, 3, 1, 1.5, 2, half_plane);
+        assert_eq!(pixel(&multi, 3, 0, 0), [255, 0, 0, 255]);
+        assert_eq!(pixel(&multi, 3, 0, 1), [255, 0, 0, 128]);
+        assert_eq!(pixel(&multi, 3, 0, 2), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_render_identity() {
+        let buffer = render_to_buffer(&MobiusTransform::identity(), 4, 4, 2.0, quadrant_color);
+        assert_eq!(buffer.len(), 4 * 4 * 4);
+
+        // Top-left pixel samples -2 + 2i
+        assert_eq!(pixel(&buffer, 4, 0, 0), [0, 1, 0, 255]);
+        // Bottom-right pixel samples 1 - 1i
+        assert_eq!(pixel(&buffer, 4, 3, 3), [1, 0, 0, 255]);
+        // Row 2, column 2 samples the origin
+        assert_eq!(pixel(&buffer, 4, 2, 2), [1, 1, 0, 255]);
+    }
+
+    #[test]
+    fn test_render_applies_transform() {
+        // z ↦ -z swaps the quadrants of the top-left sample -2 + 2i
+        let negation = MobiusTransform::rotation(std::f64::consts::PI).unwrap();
+        let buffer = render_to_buffer(&negation, 4, 4, 2.0, quadrant_color);
+        assert_eq!(pixel(&buffer, 4, 0, 0), [1, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_fit_viewport() {
+        // z ↦ 2z + (1 + i) maps the square [-1, 1]² onto [-1, 3]²
+        let m = MobiusTransform::from_affine(Complex64::new(2.0, 0.0), Complex64::new(1.0, 1.0)).unwrap();
+        let corners = [
+            Complex64::new(-1.0, -1.0),
+            Complex64::new(1.0, -1.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(-1.0, 1.0),
+            Complex64::new(0.0, 0.0),
+        ];
+        assert_eq!(fit_viewport(&m, &corners), (-1.0, 3.0, -1.0, 3.0));
+    }
+
+    #[test]
+    fn test_fit_viewport_ignores_infinity() {
+        // z ↦ 1/z sends 0 to infinity
+        let inversion = MobiusTransform::new(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap();
+        let samples = [Complex64::new(0.0, 0.0), Complex64::new(2.0, 0.0), Complex64::new(0.0, 4.0)];
+        assert_eq!(fit_viewport(&inversion, &samples), (0.0, 0.5, -0.25, 0.0));
+
+        let (x_min, x_max, _, _) = fit_viewport(&inversion, &samples[..1]);
+        assert!(x_min > x_max);
+    }
+
+    #[test]
+    fn test_render_empty() {
+        let buffer = render_to_buffer(&MobiusTransform::identity(), 0, 5, 2.0, quadrant_color);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_pixel_coordinates_round_trip() {
+        let (width, height) = (7, 5);
+        let bounds = (-1.0, 3.0, -2.0, 0.5);
+        let pixel_width = 4.0 / width as f64;
+        let pixel_height = 2.5 / height as f64;
+        for row in 0..height {
+            for col in 0..width {
+                let corner = pixel_to_complex(col, row, width, height, bounds);
+                let center = corner + Complex64::new(pixel_width / 2.0, -pixel_height / 2.0);
+                assert_eq!(complex_to_pixel(center, width, height, bounds), Some((col, row)));
+            }
+        }
+        assert_eq!(pixel_to_complex(0, 0, width, height, bounds), Complex64::new(-1.0, 0.5));
+    }
+
+    #[test]
+    fn test_complex_to_pixel_outside() {
+        let bounds = (-2.0, 2.0, -2.0, 2.0);
+        assert_eq!(complex_to_pixel(crate::complex_utils::COMPLEX_INFINITY, 4, 4, bounds), None);
+        assert_eq!(complex_to_pixel(Complex64::new(f64::NAN, 0.0), 4, 4, bounds), None);
+        assert_eq!(complex_to_pixel(Complex64::new(2.5, 0.0), 4, 4, bounds), None);
+        assert_eq!(complex_to_pixel(Complex64::new(0.0, 2.0), 4, 4, bounds), Some((2, 0)));
+        assert_eq!(complex_to_pixel(Complex64::new(0.0, -2.0), 4, 4, bounds), None);
+    }
+}
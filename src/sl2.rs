@@ -0,0 +1,253 @@
+//! Closed-form 2×2 matrix functions on SL(2,C).
+//!
+//! Matrices are stored row-major as [[a, b], [c, d]]. The formulas rely on the
+//! Cayley–Hamilton theorem, so they need no eigendecomposition and remain valid for
+//! non-diagonalizable (parabolic) matrices.
+
+use num_complex::Complex64;
+
+/// A 2×2 complex matrix in row-major order.
+pub(crate) type Matrix2 = [[Complex64; 2]; 2];
+
+/// Below this magnitude of θ the series expansion of θ/sinh θ is used.
+const SERIES_THRESHOLD: f64 = 1e-4;
+
+/// Multiplies every entry of a matrix by a scalar.
+pub(crate) fn scale(m: &Matrix2, factor: Complex64) -> Matrix2 {
+    [
+        [m[0][0] * factor, m[0][1] * factor],
+        [m[1][0] * factor, m[1][1] * factor],
+    ]
+}
+
+/// Returns the Frobenius norm of a matrix.
+pub(crate) fn frobenius_norm(m: &Matrix2) -> f64 {
+    m.iter()
+        .flatten()
+        .map(|z| z.norm_sqr())
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Returns the determinant ad - bc, accurate even when ad and bc nearly cancel.
+///
+/// Each component is a sum of four products, evaluated with error-free products
+/// (via fused multiply-add) and compensated summation, so the result is as accurate
+/// as if computed in twice the working precision and then rounded: the relative
+/// error is about machine epsilon plus eps² times the cancellation ratio
+/// (|ad| + |bc|) / |ad - bc|.
+pub(crate) fn determinant(m: &Matrix2) -> Complex64 {
+    let [[a, b], [c, d]] = *m;
+    let re = dot([(a.re, d.re), (-a.im, d.im), (-b.re, c.re), (b.im, c.im)]);
+    let im = dot([(a.re, d.im), (a.im, d.re), (-b.re, c.im), (-b.im, c.re)]);
+    Complex64::new(re, im)
+}
+
+/// Sums products in doubled precision (Ogita, Rump and Oishi's Dot2).
+fn dot<const N: usize>(terms: [(f64, f64); N]) -> f64 {
+    let mut sum = 0.0;
+    let mut compensation = 0.0;
+    for (x, y) in terms {
+        let product = x * y;
+        let product_error = x.mul_add(y, -product);
+        // TwoSum of the running sum and the product
+        let new_sum = sum + product;
+        let virtual_product = new_sum - sum;
+        let sum_error = (sum - (new_sum - virtual_product)) + (product - virtual_product);
+        sum = new_sum;
+        compensation += product_error + sum_error;
+    }
+    sum + compensation
+}
+
+/// Flips the sign of a determinant-one matrix so that Re(tr) >= 0.
+///
+/// M and -M represent the same Möbius transformation; this picks the representative
+/// whose logarithm has the smallest rotation.
+pub(crate) fn with_positive_trace(m: &Matrix2) -> Matrix2 {
+    let trace = m[0][0] + m[1][1];
+    if trace.re < 0.0 || (trace.re == 0.0 && trace.im < 0.0) {
+        scale(m, Complex64::new(-1.0, 0.0))
+    } else {
+        *m
+    }
+}
+
+/// Returns the principal logarithm of a determinant-one matrix.
+///
+/// The result X is traceless with exp(X) = M. Writing tr M = 2 cosh θ with θ the
+/// principal arccosh, X = (θ / sinh θ)(M - cosh θ · I). When θ = 0 (M parabolic or
+/// the identity) this reduces to X = M - I, which is exact because (M - I)² = 0.
+///
+/// The caller is expected to pass a matrix with Re(tr M) >= 0 (see
+/// [`with_positive_trace`]); for tr M = -2 the logarithm does not exist in sl(2,C)
+/// unless M = -I.
+pub(crate) fn log(m: &Matrix2) -> Matrix2 {
+    let half_trace = (m[0][0] + m[1][1]) / 2.0;
+    let theta = half_trace.acosh();
+    let factor = if theta.norm() < SERIES_THRESHOLD {
+        // θ / sinh θ = 1 - θ²/6 + O(θ⁴)
+        Complex64::new(1.0, 0.0) - theta * theta / 6.0
+    } else {
+        theta / theta.sinh()
+    };
+    [
+        [(m[0][0] - half_trace) * factor, m[0][1] * factor],
+        [m[1][0] * factor, (m[1][1] - half_trace) * factor],
+    ]
+}
+
+/// Returns the exponential of a traceless matrix.
+///
+/// For traceless X, X² = s² I with s² = -det X, so
+/// exp(X) = cosh s · I + (sinh s / s) X. This depends only on s², so either square
+/// root may be used. The result has determinant one.
+pub(crate) fn exp(x: &Matrix2) -> Matrix2 {
+    let s = (-(x[0][0] * x[1][1] - x[0][1] * x[1][0])).sqrt();
+    let factor = if s.norm() < SERIES_THRESHOLD {
+        // sinh s / s = 1 + s²/6 + O(s⁴)
+        Complex64::new(1.0, 0.0) + s * s / 6.0
+    } else {
+        s.sinh() / s
+    };
+    let cosh = s.cosh();
+    [
+        [cosh + x[0][0] * factor, x[0][1] * factor],
+        [x[1][0] * factor, cosh + x[1][1] * factor],
+    ]
+}
+
+/// Returns a nonzero multiple of the exponential of a traceless matrix, with entries
+/// of order one however large the matrix is.
+///
+/// X has eigenvalues ±s, taking Re s >= 0, and for s ≠ 0 the projections
+/// P± = (I ± X/s) / 2 onto their eigenvectors give exp(X) = e^s P₊ + e^{-s} P₋. The
+/// result is e^{-s} exp(X) = P₊ + e^{-2s} P₋, which does not overflow for large Re s,
+/// and describes the same Möbius transformation as [`exp`] with a determinant e^{-2s}
+/// that may be tiny. The diagonals of P± involve u = s + x₁₁ and v = s - x₁₁, one of
+/// which may cancel; since uv = x₁₂x₂₁ the smaller is computed from the larger, so
+/// small entries keep their relative accuracy. Small |s| uses [`exp`] itself.
+pub(crate) fn exp_projective(x: &Matrix2) -> Matrix2 {
+    let s = (-(x[0][0] * x[1][1] - x[0][1] * x[1][0])).sqrt();
+    if s.norm() < 1.0 {
+        return exp(x);
+    }
+    let s = if s.re < 0.0 { -s } else { s };
+    let (sum, difference) = (s + x[0][0], s - x[0][0]);
+    let product = x[0][1] * x[1][0];
+    let (u, v) = if sum.norm() >= difference.norm() {
+        (sum, product / sum)
+    } else {
+        (product / difference, difference)
+    };
+
+    // P₊ = [[u, x₁₂], [x₂₁, v]] / 2s and P₋ = [[v, -x₁₂], [-x₂₁, u]] / 2s
+    let decay = (-2.0 * s).exp();
+    let one = Complex64::new(1.0, 0.0);
+    let scale = (2.0 * s).inv();
+    [
+        [(u + decay * v) * scale, x[0][1] * (one - decay) * scale],
+        [x[1][0] * (one - decay) * scale, (v + decay * u) * scale],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(m1: &Matrix2, m2: &Matrix2) {
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((m1[i][j] - m2[i][j]).norm() < 1e-10, "{:?} != {:?}", m1, m2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_log_known_values() {
+        let one = Complex64::new(1.0, 0.0);
+        let zero = Complex64::new(0.0, 0.0);
+
+        // Parabolic: log [[1, t], [0, 1]] = [[0, t], [0, 0]]
+        let t = Complex64::new(2.0, -1.0);
+        assert_close(&log(&[[one, t], [zero, one]]), &[[zero, t], [zero, zero]]);
+
+        // Diagonal: log diag(k, 1/k) = diag(ln k, -ln k)
+        let k = Complex64::new(1.5, 0.7);
+        let ln_k = k.ln();
+        assert_close(&log(&[[k, zero], [zero, k.inv()]]), &[[ln_k, zero], [zero, -ln_k]]);
+
+        // Identity
+        assert_close(&log(&[[one, zero], [zero, one]]), &[[zero, zero], [zero, zero]]);
+    }
+
+    #[test]
+    fn test_exp_inverts_log() {
+        let m = [
+            [Complex64::new(2.0, 1.0), Complex64::new(1.0, 0.0)],
+            [Complex64::new(1.0, 1.0), Complex64::new(1.0, -0.5)],
+        ];
+        let det = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+        let m = with_positive_trace(&scale(&m, det.sqrt().inv()));
+        assert_close(&exp(&log(&m)), &m);
+
+        // Nilpotent generator: exp [[0, t], [0, 0]] = [[1, t], [0, 1]]
+        let one = Complex64::new(1.0, 0.0);
+        let zero = Complex64::new(0.0, 0.0);
+        let t = Complex64::new(-1.0, 3.0);
+        assert_close(&exp(&[[zero, t], [zero, zero]]), &[[one, t], [zero, one]]);
+    }
+
+    #[test]
+    fn test_determinant_with_cancellation() {
+        // [[1 + e, 1], [1, 1 - e]] with e = 2⁻³⁰ has determinant -e² exactly, while
+        // (1 + e)(1 - e) = 1 - 2⁻⁶⁰ rounds to 1 in plain arithmetic
+        let e = 2.0_f64.powi(-30);
+        let m = [
+            [Complex64::new(1.0 + e, 0.0), Complex64::new(1.0, 0.0)],
+            [Complex64::new(1.0, 0.0), Complex64::new(1.0 - e, 0.0)],
+        ];
+        assert_eq!(determinant(&m), Complex64::new(-e * e, 0.0));
+
+        let plain = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+        assert_eq!(plain.re, 0.0);
+    }
+
+    #[test]
+    fn test_log_is_traceless() {
+        let m = [
+            [Complex64::new(2.0, 1.0), Complex64::new(1.0, 0.0)],
+            [Complex64::new(1.0, 1.0), Complex64::new(1.0, -0.5)],
+        ];
+        // Normalize to det = 1
+        let det = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+        let m = scale(&m, det.sqrt().inv());
+        let x = log(&with_positive_trace(&m));
+        assert!((x[0][0] + x[1][1]).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_exp_projective() {
+        let x = [
+            [Complex64::new(1.5, 0.5), Complex64::new(-2.0, 1.0)],
+            [Complex64::new(0.5, 0.0), Complex64::new(-1.5, -0.5)],
+        ];
+        // Proportional to exp(X), with factor e^{-s}
+        let (m, projective) = (exp(&x), exp_projective(&x));
+        let factor = projective[0][0] / m[0][0];
+        assert_close(&scale(&m, factor), &projective);
+
+        // diag(s, -s) for large s: e^{-s} exp(X) = diag(1, e^{-2s}), without overflow
+        let s = Complex64::new(300.0, 0.0);
+        let zero = Complex64::new(0.0, 0.0);
+        let projective = exp_projective(&[[s, zero], [zero, -s]]);
+        assert_eq!(projective[0][0], Complex64::new(1.0, 0.0));
+        assert!((projective[1][1].re / (-600.0_f64).exp() - 1.0).abs() < 1e-12);
+        assert_eq!([projective[0][1], projective[1][0]], [zero, zero]);
+
+        // and diag(-s, s) gives diag(e^{-2s}, 1), not a rounding error in the first entry
+        let projective = exp_projective(&[[-s, zero], [zero, s]]);
+        assert!((projective[0][0].re / (-600.0_f64).exp() - 1.0).abs() < 1e-12);
+        assert_eq!(projective[1][1], Complex64::new(1.0, 0.0));
+    }
+}
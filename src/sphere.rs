@@ -0,0 +1,240 @@
+//! The action of Möbius transformations on the Riemann sphere.
+//!
+//! Points of the plane are identified with the unit sphere in R³ by stereographic
+//! projection from the north pole: z = (x + iy) / (1 - x₃), so that 0 is the south
+//! pole (0, 0, -1), infinity the north pole (0, 0, 1), and the unit circle the
+//! equator. Under this identification the rotations of the sphere are exactly the
+//! transformations with a unitary matrix, PSU(2) ≅ SO(3).
+
+use num_complex::Complex64;
+use crate::complex_utils::is_infinity;
+use crate::transforms::{MobiusTransform, TransformError, DEFAULT_TOLERANCE};
+
+impl MobiusTransform {
+    /// Creates the transformation that rotates the Riemann sphere by `angle` about
+    /// `axis`, counterclockwise when looking from the tip of the axis towards the origin.
+    ///
+    /// With unit axis n and half-angle φ = angle / 2 the matrix is the unitary
+    /// [[α, β], [-β̄, ᾱ]] with α = cos φ + i n₃ sin φ and β = (i n₁ - n₂) sin φ. The
+    /// axis (0, 0, 1) gives the rotation z ↦ e^{i·angle} z.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InvalidArgument` if the axis is zero or not finite.
+    /// Returns `TransformError::InfiniteCoefficient` if the angle is infinite or NaN.
+    pub fn from_sphere_rotation(axis: [f64; 3], angle: f64) -> Result<Self, TransformError> {
+        let length = axis.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if !length.is_finite() || length == 0.0 {
+            return Err(TransformError::InvalidArgument(
+                "rotation axis must be finite and non-zero".to_string(),
+            ));
+        }
+        let [n1, n2, n3] = axis.map(|x| x / length);
+        let (sin, cos) = (angle / 2.0).sin_cos();
+
+        let alpha = Complex64::new(cos, n3 * sin);
+        let beta = Complex64::new(-n2 * sin, n1 * sin);
+        Self::new(alpha, beta, -beta.conj(), alpha.conj())
+    }
+
+    /// Creates the involution exchanging `z1` and `z2` that is a rotation of the sphere.
+    ///
+    /// Many involutions swap two given points; this returns the half-turn of the
+    /// Riemann sphere about the axis through the spherical midpoint of z1 and z2,
+    /// which fixes that midpoint and its antipode. The result satisfies f(z1) = z2,
+    /// f(z2) = z1 and f ∘ f = identity. Either point may be infinity. When z1 and z2
+    /// are antipodal (z2 = -1/z̄1) every great circle through them has a midpoint, and
+    /// the axis is taken perpendicular to z1 and to the coordinate axis least aligned
+    /// with it.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InvalidArgument` if either point is NaN or the points
+    /// coincide (within [`DEFAULT_TOLERANCE`] in chordal distance).
+    pub fn swap(z1: Complex64, z2: Complex64) -> Result<MobiusTransform, TransformError> {
+        if z1.is_nan() || z2.is_nan() {
+            return Err(TransformError::InvalidArgument("points must not be NaN".to_string()));
+        }
+        let (p1, p2) = (to_sphere(z1), to_sphere(z2));
+        let difference = [0, 1, 2].map(|i| p1[i] - p2[i]);
+        if norm(difference) < DEFAULT_TOLERANCE {
+            return Err(TransformError::InvalidArgument("points to swap must be distinct".to_string()));
+        }
+
+        let midpoint = [0, 1, 2].map(|i| p1[i] + p2[i]);
+        let axis = if norm(midpoint) > DEFAULT_TOLERANCE {
+            midpoint
+        } else {
+            // Antipodal points: any axis perpendicular to p1 works
+            let k = (0..3)
+                .min_by(|&i, &j| p1[i].abs().total_cmp(&p1[j].abs()))
+                .unwrap_or(0);
+            let mut e = [0.0; 3];
+            e[k] = 1.0;
+            [
+                p1[1] * e[2] - p1[2] * e[1],
+                p1[2] * e[0] - p1[0] * e[2],
+                p1[0] * e[1] - p1[1] * e[0],
+            ]
+        };
+        Self::from_sphere_rotation(axis, std::f64::consts::PI)
+    }
+
+    /// Returns the 3×3 rotation matrix of the action on the Riemann sphere.
+    ///
+    /// Returns `None` unless the transformation is a rotation of the sphere, i.e. its
+    /// normalized matrix is unitary up to [`DEFAULT_TOLERANCE`]. Such transformations
+    /// are the identity and the elliptic maps whose fixed points are antipodal. The
+    /// normalized matrix [[α, β], [-β̄, ᾱ]] corresponds to the unit quaternion
+    /// (Re α, Im β, -Re β, Im α), whose rotation matrix is returned; it inverts
+    /// [`MobiusTransform::from_sphere_rotation`].
+    pub fn to_so3(&self) -> Option<[[f64; 3]; 3]> {
+        let n = self.normalized_matrix();
+        let (alpha, beta) = (n[0][0], n[0][1]);
+        if (n[1][1] - alpha.conj()).norm() > DEFAULT_TOLERANCE
+            || (n[1][0] + beta.conj()).norm() > DEFAULT_TOLERANCE
+        {
+            return None;
+        }
+
+        let (w, x, y, z) = (alpha.re, beta.im, -beta.re, alpha.im);
+        Some([
+            [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - z * w), 2.0 * (x * z + y * w)],
+            [2.0 * (x * y + z * w), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - x * w)],
+            [2.0 * (x * z - y * w), 2.0 * (y * z + x * w), 1.0 - 2.0 * (x * x + y * y)],
+        ])
+    }
+}
+
+/// Inverse stereographic projection, with infinity at the north pole.
+///
+/// Points outside the unit circle are projected through w = 1/z, so huge points do
+/// not overflow |z|².
+fn to_sphere(z: Complex64) -> [f64; 3] {
+    if is_infinity(z) {
+        return [0.0, 0.0, 1.0];
+    }
+    if z.norm() <= 1.0 {
+        let d = 1.0 + z.norm_sqr();
+        [2.0 * z.re / d, 2.0 * z.im / d, (z.norm_sqr() - 1.0) / d]
+    } else {
+        // z / (1 + |z|²) = w̄ / (1 + |w|²)
+        let w = z.inv();
+        let d = 1.0 + w.norm_sqr();
+        [2.0 * w.re / d, -2.0 * w.im / d, (1.0 - w.norm_sqr()) / d]
+    }
+}
+
+/// Returns the Euclidean norm of a vector.
+fn norm(v: [f64; 3]) -> f64 {
+    v.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex_utils::COMPLEX_INFINITY;
+
+    fn rotate(r: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+        [0, 1, 2].map(|i| (0..3).map(|k| r[i][k] * v[k]).sum())
+    }
+
+    /// Rotation matrix about a unit axis by Rodrigues' formula.
+    fn rodrigues(n: [f64; 3], angle: f64) -> [[f64; 3]; 3] {
+        let k = [[0.0, -n[2], n[1]], [n[2], 0.0, -n[0]], [-n[1], n[0], 0.0]];
+        let mut r = [[0.0; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                let k_squared: f64 = (0..3).map(|l| k[i][l] * k[l][j]).sum();
+                r[i][j] = f64::from(u8::from(i == j)) + angle.sin() * k[i][j] + (1.0 - angle.cos()) * k_squared;
+            }
+        }
+        r
+    }
+
+    fn assert_matrix_close(r1: &[[f64; 3]; 3], r2: &[[f64; 3]; 3]) {
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((r1[i][j] - r2[i][j]).abs() < 1e-12, "{:?} != {:?}", r1, r2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip_through_so3() {
+        let length = (0.09_f64 + 0.25 + 0.64).sqrt();
+        let axis = [0.3 / length, -0.5 / length, 0.8 / length];
+        let m = MobiusTransform::from_sphere_rotation(axis, 0.9).unwrap();
+        let r = m.to_so3().unwrap();
+        assert_matrix_close(&r, &rodrigues(axis, 0.9));
+
+        // The matrix describes the action of the map on the sphere
+        for z in [Complex64::new(0.3, 0.2), Complex64::new(-1.0, 2.0), Complex64::new(0.0, 0.1)] {
+            let image = to_sphere(m.apply(z));
+            let rotated = rotate(&r, to_sphere(z));
+            for i in 0..3 {
+                assert!((image[i] - rotated[i]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotation_about_vertical_axis() {
+        // Not normalized on purpose
+        let m = MobiusTransform::from_sphere_rotation([0.0, 0.0, 2.0], 0.7).unwrap();
+        assert!(m.approx_eq(&MobiusTransform::rotation(0.7).unwrap()));
+
+        // Both signs of the normalized matrix give the same rotation
+        let r = MobiusTransform::rotation(0.7).unwrap().to_so3().unwrap();
+        assert_matrix_close(&r, &rodrigues([0.0, 0.0, 1.0], 0.7));
+        assert_matrix_close(&MobiusTransform::identity().to_so3().unwrap(), &rodrigues([1.0, 0.0, 0.0], 0.0));
+    }
+
+    #[test]
+    fn test_to_so3_rejects_non_rotations() {
+        let scaling = MobiusTransform::from_affine(Complex64::new(2.0, 0.0), Complex64::new(0.0, 0.0)).unwrap();
+        assert!(scaling.to_so3().is_none());
+        let translation = MobiusTransform::translation(Complex64::new(1.0, 0.0)).unwrap();
+        assert!(translation.to_so3().is_none());
+    }
+
+    #[test]
+    fn test_swap() {
+        let pairs = [
+            (Complex64::new(0.5, 1.0), Complex64::new(-2.0, 0.3)),
+            (Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)),
+            (Complex64::new(3.0, -1.0), COMPLEX_INFINITY),
+            // Antipodal: 0 and ∞, and z and -1/z̄
+            (Complex64::new(0.0, 0.0), COMPLEX_INFINITY),
+            (Complex64::new(1.0, 2.0), -Complex64::new(1.0, 2.0).conj().inv()),
+        ];
+        for (z1, z2) in pairs {
+            let f = MobiusTransform::swap(z1, z2).unwrap();
+            assert!(crate::complex_utils::chordal_distance(f.apply(z1), z2) < 1e-12, "{} -> {}", z1, z2);
+            assert!(crate::complex_utils::chordal_distance(f.apply(z2), z1) < 1e-12, "{} -> {}", z2, z1);
+            assert!(f.compose(&f).approx_eq(&MobiusTransform::identity()));
+            assert!(f.is_involution(1e-10));
+            assert!(f.to_so3().is_some());
+        }
+
+        // 0 and 1 have the spherical midpoint (√2 - 1), on the real axis
+        let f = MobiusTransform::swap(Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)).unwrap();
+        assert!(f.fixes(Complex64::new(2.0_f64.sqrt() - 1.0, 0.0), 1e-12));
+    }
+
+    #[test]
+    fn test_swap_rejects_coincident_points() {
+        let z = Complex64::new(1.0, -1.0);
+        assert!(matches!(MobiusTransform::swap(z, z), Err(TransformError::InvalidArgument(_))));
+        assert!(MobiusTransform::swap(COMPLEX_INFINITY, Complex64::new(f64::INFINITY, 0.0)).is_err());
+        assert!(MobiusTransform::swap(z, Complex64::new(f64::NAN, 0.0)).is_err());
+    }
+
+    #[test]
+    fn test_invalid_axis() {
+        assert!(matches!(
+            MobiusTransform::from_sphere_rotation([0.0, 0.0, 0.0], 1.0),
+            Err(TransformError::InvalidArgument(_))
+        ));
+        assert!(MobiusTransform::from_sphere_rotation([f64::NAN, 0.0, 1.0], 1.0).is_err());
+        assert!(MobiusTransform::from_sphere_rotation([0.0, 0.0, 1.0], f64::INFINITY).is_err());
+    }
+}
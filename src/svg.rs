@@ -0,0 +1,211 @@
+//! Vector export of transformed grids as SVG.
+//!
+//! Unlike [`render`](crate::render), which samples the plane pixel by pixel, this
+//! module maps the grid lines themselves, producing resolution-independent figures.
+
+use std::fmt::Write;
+use num_complex::Complex64;
+use crate::plane_functions::GridConfig;
+use crate::transforms::MobiusTransform;
+
+/// Number of samples along each grid line before mapping.
+const SAMPLES_PER_LINE: usize = 256;
+
+/// Stroke colors of the grids, matching the `visualize` example.
+const VERTICAL_COLOR: &str = "#ff0000";
+const HORIZONTAL_COLOR: &str = "#0000ff";
+const RADIAL_COLOR: &str = "#00ff00";
+const ANGULAR_COLOR: &str = "#ff00ff";
+
+/// Returns an SVG document showing the image of the grid under `transform`.
+///
+/// The pre-image grids are those tested by
+/// [`classify_grid`](crate::plane_functions::classify_grid), restricted to the square
+/// [-range, range] × [-range, range]: vertical and horizontal lines at multiples of
+/// their periods, and circles about the origin and rays from it at odd multiples of
+/// half the radial and angular periods (angles measured in [0, 2π)), the phases of
+/// [`radial_grid`](crate::plane_functions::radial_grid) and
+/// [`angular_grid`](crate::plane_functions::angular_grid). Each line is sampled, mapped
+/// through the transformation, and drawn as a `<path>` of straight segments, in the
+/// colors of the `visualize` example. Lines are twice the configured half-width
+/// (angular half-widths are taken at unit radius).
+///
+/// The view box is the same square, with the imaginary axis pointing up. Möbius maps
+/// send lines through the pole to curves through infinity, so a path is broken where
+/// its pre-image passes within one sample spacing of the pole. Grids with a
+/// non-positive or non-finite period are omitted.
+///
+/// # Examples
+/// ```
+/// use mobius_applicatio::MobiusTransform;
+/// use mobius_applicatio::plane_functions::GridConfig;
+/// use mobius_applicatio::svg::grid_to_svg;
+///
+/// let svg = grid_to_svg(&MobiusTransform::identity(), &GridConfig::default(), 1.0);
+/// assert!(svg.starts_with("<svg"));
+/// ```
+pub fn grid_to_svg(transform: &MobiusTransform, config: &GridConfig, range: f64) -> String {
+    let mut svg = String::new();
+    let size = 2.0 * range;
+    // Writing to a String cannot fail
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+        -range, -range, size, size
+    );
+    // Flip the y axis so the imaginary axis points up
+    let _ = writeln!(svg, r#"<g transform="scale(1,-1)" fill="none">"#);
+
+    let pole = transform.pole();
+    let mut draw = |samples: Vec<Complex64>, color: &str, thickness: f64| {
+        for piece in map_polyline(transform, pole, &samples) {
+            write_path(&mut svg, &piece, color, 2.0 * thickness);
+        }
+    };
+
+    for x in lattice(config.vertical_period, 0.0, -range, range) {
+        draw(segment(Complex64::new(x, -range), Complex64::new(x, range)), VERTICAL_COLOR, config.vertical_thickness);
+    }
+    for y in lattice(config.horizontal_period, 0.0, -range, range) {
+        draw(segment(Complex64::new(-range, y), Complex64::new(range, y)), HORIZONTAL_COLOR, config.horizontal_thickness);
+    }
+    for radius in lattice(config.radial_period, config.radial_period / 2.0, 0.0, range) {
+        let circle = (0..=SAMPLES_PER_LINE)
+            .map(|k| Complex64::from_polar(radius, 2.0 * std::f64::consts::PI * k as f64 / SAMPLES_PER_LINE as f64))
+            .collect();
+        draw(circle, RADIAL_COLOR, config.radial_thickness);
+    }
+    let full_turn = 2.0 * std::f64::consts::PI;
+    let angles = lattice(config.angular_period, config.angular_period / 2.0, 0.0, full_turn)
+        .filter(|&angle| angle < full_turn);
+    for angle in angles {
+        let end = Complex64::from_polar(range, angle);
+        draw(segment(Complex64::new(0.0, 0.0), end), ANGULAR_COLOR, config.angular_thickness);
+    }
+
+    svg.push_str("</g>\n</svg>\n");
+    svg
+}
+
+/// Returns the values offset + k·period in [start, end], or nothing for an invalid period.
+fn lattice(period: f64, offset: f64, start: f64, end: f64) -> impl Iterator<Item = f64> {
+    let (first, last) = if period > 0.0 && period.is_finite() && offset.is_finite() && start <= end {
+        (((start - offset) / period).ceil() as i64, ((end - offset) / period).floor() as i64)
+    } else {
+        (0, -1)
+    };
+    (first..=last).map(move |k| offset + k as f64 * period)
+}
+
+/// Returns evenly spaced samples from `start` to `end`, inclusive.
+fn segment(start: Complex64, end: Complex64) -> Vec<Complex64> {
+    (0..=SAMPLES_PER_LINE)
+        .map(|k| start + (end - start) * (k as f64 / SAMPLES_PER_LINE as f64))
+        .collect()
+}
+
+/// Maps a polyline through the transformation, splitting it into pieces wherever an
+/// edge passes within its own length of the pole or an image is not finite.
+fn map_polyline(transform: &MobiusTransform, pole: Complex64, samples: &[Complex64]) -> Vec<Vec<Complex64>> {
+    let mut pieces = Vec::new();
+    let mut current: Vec<Complex64> = Vec::new();
+
+    for (i, &z) in samples.iter().enumerate() {
+        let near_pole = i > 0 && {
+            let previous = samples[i - 1];
+            pole.is_finite() && distance_to_segment(pole, previous, z) < (z - previous).norm()
+        };
+        let image = transform.apply(z);
+        if near_pole || !image.is_finite() {
+            if current.len() > 1 {
+                pieces.push(std::mem::take(&mut current));
+            }
+            current.clear();
+        }
+        if image.is_finite() {
+            current.push(image);
+        }
+    }
+    if current.len() > 1 {
+        pieces.push(current);
+    }
+    pieces
+}
+
+/// Returns the distance from `p` to the segment from `start` to `end`.
+fn distance_to_segment(p: Complex64, start: Complex64, end: Complex64) -> f64 {
+    let direction = end - start;
+    let length_sqr = direction.norm_sqr();
+    if length_sqr == 0.0 {
+        return (p - start).norm();
+    }
+    let t = ((p - start) * direction.conj()).re / length_sqr;
+    (p - (start + direction * t.clamp(0.0, 1.0))).norm()
+}
+
+/// Appends a `<path>` element through the given points.
+fn write_path(svg: &mut String, points: &[Complex64], color: &str, width: f64) {
+    svg.push_str("<path d=\"");
+    for (i, w) in points.iter().enumerate() {
+        let command = if i == 0 { 'M' } else { 'L' };
+        let _ = write!(svg, "{}{:.6} {:.6}", command, w.re, w.im);
+    }
+    let _ = writeln!(svg, r#"" stroke="{}" stroke-width="{}"/>"#, color, width);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_to_svg_is_well_formed() {
+        let config = GridConfig {
+            vertical_period: 0.5,
+            horizontal_period: 0.5,
+            radial_period: 0.5,
+            angular_period: std::f64::consts::FRAC_PI_2,
+            ..GridConfig::default()
+        };
+        let svg = grid_to_svg(&MobiusTransform::identity(), &config, 1.0);
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<g ").count(), svg.matches("</g>").count());
+        // 5 vertical, 5 horizontal, 2 circles (radii 0.25 and 0.75) and 4 rays (at
+        // odd multiples of π/4), each an unbroken path
+        assert_eq!(svg.matches("<path ").count(), 16);
+        assert_eq!(svg.matches("<path ").count(), svg.matches("/>").count());
+        assert_eq!(svg.matches(VERTICAL_COLOR).count(), 5);
+    }
+
+    #[test]
+    fn test_grid_to_svg_breaks_paths_at_the_pole() {
+        // f(z) = 1/z has its pole at the origin, on the axes and at the rays' start
+        let inversion = MobiusTransform::new(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap();
+        let config = GridConfig {
+            vertical_period: 0.5,
+            horizontal_period: 0.5,
+            radial_period: 0.5,
+            angular_period: std::f64::consts::FRAC_PI_2,
+            ..GridConfig::default()
+        };
+        let svg = grid_to_svg(&inversion, &config, 1.0);
+
+        assert!(!svg.contains("inf") && !svg.contains("NaN"));
+        // The axes each split in two; the rays lose only their first edge
+        assert_eq!(svg.matches("<path ").count(), 18);
+    }
+
+    #[test]
+    fn test_lattice() {
+        assert_eq!(lattice(0.5, 0.0, -1.2, 1.2).collect::<Vec<_>>(), vec![-1.0, -0.5, 0.0, 0.5, 1.0]);
+        assert_eq!(lattice(0.5, 0.25, 0.0, 1.0).collect::<Vec<_>>(), vec![0.25, 0.75]);
+        assert_eq!(lattice(0.0, 0.0, -1.0, 1.0).count(), 0);
+        assert_eq!(lattice(f64::NAN, 0.0, -1.0, 1.0).count(), 0);
+    }
+}
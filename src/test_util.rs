@@ -0,0 +1,70 @@
+//! Assertions for test suites that compare Möbius transformations.
+//!
+//! Enabled with the `test-util` feature.
+
+use crate::transforms::MobiusTransform;
+
+/// Asserts that two transformations are the same map, up to a tolerance.
+///
+/// Coefficients are only defined up to a common scalar, so M and -M (or 2M) are the
+/// same map even though their fields differ. This compares the maps with
+/// [`MobiusTransform::approx_eq_with_tolerance`] and, when they differ, panics with
+/// both canonical forms (see [`MobiusTransform::canonical`]), which are directly
+/// comparable.
+///
+/// # Panics
+/// Panics if the transformations are not equal within `tol`.
+///
+/// # Examples
+/// ```
+/// use mobius_applicatio::MobiusTransform;
+/// use mobius_applicatio::test_util::assert_transforms_equal;
+/// use num_complex::Complex64;
+///
+/// let (one, two) = (Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0));
+/// let m = MobiusTransform::new(two, one, one, one).unwrap();
+/// let negated = MobiusTransform::new(-two, -one, -one, -one).unwrap();
+/// assert_transforms_equal(&m, &negated, 1e-12);
+/// ```
+#[track_caller]
+pub fn assert_transforms_equal(a: &MobiusTransform, b: &MobiusTransform, tol: f64) {
+    if !a.approx_eq_with_tolerance(b, tol) {
+        panic!(
+            "transformations differ (tolerance {:e}):\n  left:  {:.10}\n  right: {:.10}",
+            tol,
+            a.canonical(),
+            b.canonical()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex64;
+
+    fn sample() -> MobiusTransform {
+        MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap()
+    }
+
+    #[test]
+    fn test_assert_transforms_equal_up_to_scalar() {
+        let m = sample();
+        let (a, b, c, d) = m.coefficients();
+        for scale in [Complex64::new(-1.0, 0.0), Complex64::new(0.0, 2.0)] {
+            let scaled = MobiusTransform::new(a * scale, b * scale, c * scale, d * scale).unwrap();
+            assert_transforms_equal(&m, &scaled, 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "transformations differ")]
+    fn test_assert_transforms_equal_rejects_different_maps() {
+        assert_transforms_equal(&sample(), &sample().inverse(), 1e-6);
+    }
+}
@@ -5,16 +5,49 @@
 
 use num_complex::Complex64;
 use ndarray::{Array1, Array2};
-use crate::complex_utils::{is_infinity, normalize_infinity, COMPLEX_INFINITY};
+use crate::classification::TransformClass;
+use crate::complex_utils::{chordal_distance, format_complex, is_infinity, normalize_infinity, ComplexPoint, COMPLEX_INFINITY};
+use crate::sl2::{self, Matrix2};
 use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Default tolerance used to decide when a quantity is zero.
+///
+/// Used for the determinant check in [`MobiusTransform::new`] and the pole check in
+/// [`MobiusTransform::apply`].
+pub const DEFAULT_TOLERANCE: f64 = 1e-10;
+
+/// Number of steps used to measure path lengths in
+/// [`MobiusTransform::interpolate_shortest`].
+const PATH_LENGTH_STEPS: usize = 32;
+
+/// Norm of the mean logarithm below which [`MobiusTransform::mean`] has converged.
+const MEAN_TOLERANCE: f64 = 1e-12;
+
+/// Maximum number of iterations of [`MobiusTransform::mean`].
+const MAX_MEAN_ITERATIONS: usize = 100;
+
+/// Grid spacing used to quantize canonical coefficients for `PartialEq` and `Hash`.
+const EQUALITY_QUANTUM: f64 = 1e-8;
 
 /// Error type for Möbius transformation operations.
 #[derive(Debug, Clone, PartialEq)]
 pub enum TransformError {
     /// The determinant (ad - bc) is zero or too close to zero.
     SingularTransform,
-    /// One or more coefficients contain infinity.
+    /// One or more coefficients contain infinity or NaN.
     InfiniteCoefficient,
+    /// An input array does not have the shape required by the operation.
+    ShapeMismatch {
+        /// The required shape
+        expected: Vec<usize>,
+        /// The shape that was provided
+        found: Vec<usize>,
+    },
+    /// A textual representation of a transformation could not be parsed.
+    InvalidFormat(String),
+    /// The arguments do not determine a transformation, e.g. a zero rotation axis.
+    InvalidArgument(String),
 }
 
 impl fmt::Display for TransformError {
@@ -26,12 +59,33 @@ impl fmt::Display for TransformError {
             TransformError::InfiniteCoefficient => {
                 write!(f, "Coefficients must be finite for a valid Möbius transformation")
             }
+            TransformError::ShapeMismatch { expected, found } => {
+                write!(f, "Expected an array of shape {:?}, found shape {:?}", expected, found)
+            }
+            TransformError::InvalidFormat(message) => {
+                write!(f, "Invalid transformation format: {}", message)
+            }
+            TransformError::InvalidArgument(message) => {
+                write!(f, "Invalid argument: {}", message)
+            }
         }
     }
 }
 
 impl std::error::Error for TransformError {}
 
+/// Marker returned by [`MobiusTransform::try_apply`] when the image is the point at infinity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AtInfinity;
+
+impl fmt::Display for AtInfinity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The image is the point at infinity")
+    }
+}
+
+impl std::error::Error for AtInfinity {}
+
 /// Represents a Möbius transformation with complex coefficients.
 ///
 /// The transformation is defined as: f(z) = (az + b) / (cz + d)
@@ -50,22 +104,74 @@ pub struct MobiusTransform {
 impl MobiusTransform {
     /// Creates a new Möbius transformation.
     ///
+    /// Uses [`DEFAULT_TOLERANCE`] for the determinant check; see
+    /// [`MobiusTransform::new_with_tolerance`] to configure it.
+    ///
     /// # Errors
-    /// Returns `TransformError::InfiniteCoefficient` if any coefficient is infinite.
+    /// Returns `TransformError::InfiniteCoefficient` if any coefficient is infinite or NaN.
     /// Returns `TransformError::SingularTransform` if the determinant (ad - bc) is zero.
     pub fn new(a: Complex64, b: Complex64, c: Complex64, d: Complex64) -> Result<Self, TransformError> {
-        if is_infinity(a) || is_infinity(b) || is_infinity(c) || is_infinity(d) {
+        Self::new_with_tolerance(a, b, c, d, DEFAULT_TOLERANCE)
+    }
+
+    /// Creates a new Möbius transformation with a custom singularity tolerance.
+    ///
+    /// The transformation is rejected as singular when |ad - bc| <= `tol`. The check is
+    /// absolute, so the appropriate tolerance depends on the magnitude of the coefficients:
+    /// a smaller tolerance accepts transformations with tiny coefficients (or nearly
+    /// proportional rows) that are still mathematically valid, at the cost of admitting
+    /// ill-conditioned maps whose inverse and normalization lose precision. A larger
+    /// tolerance rejects more of those maps up front.
+    ///
+    /// A zero determinant is rejected whatever the tolerance (a negative `tol` acts as
+    /// zero). In particular a = c = 0 is always rejected: it describes the constant map
+    /// z ↦ b/d, which is not a Möbius transformation.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InfiniteCoefficient` if any coefficient is infinite or NaN.
+    /// Returns `TransformError::SingularTransform` if |ad - bc| <= `tol`, or ad - bc = 0.
+    pub fn new_with_tolerance(
+        a: Complex64,
+        b: Complex64,
+        c: Complex64,
+        d: Complex64,
+        tol: f64,
+    ) -> Result<Self, TransformError> {
+        if !(a.is_finite() && b.is_finite() && c.is_finite() && d.is_finite()) {
             return Err(TransformError::InfiniteCoefficient);
         }
         
         let det = a * d - b * c;
-        if det.norm() <= 1e-10 {
+        // A NaN determinant (from overflow in ad - bc) cannot be trusted either
+        if det.norm().is_nan() || det.norm() <= tol.max(0.0) {
             return Err(TransformError::SingularTransform);
         }
         
         Ok(Self { a, b, c, d })
     }
 
+    /// Creates a new Möbius transformation and reports its condition number.
+    ///
+    /// Unlike [`MobiusTransform::new`], no determinant cutoff is applied: any matrix with
+    /// a non-zero determinant is accepted, however ill-conditioned, and the
+    /// condition number (see [`MobiusTransform::condition_number`]) is returned
+    /// alongside so the caller can decide whether the map is numerically usable.
+    /// The condition number is scale invariant, so this also accepts maps with tiny or
+    /// huge coefficients that the absolute cutoff of `new` rejects.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InfiniteCoefficient` if any coefficient is infinite or NaN.
+    /// Returns `TransformError::SingularTransform` if the determinant (ad - bc) is exactly zero.
+    pub fn new_checked(
+        a: Complex64,
+        b: Complex64,
+        c: Complex64,
+        d: Complex64,
+    ) -> Result<(Self, f64), TransformError> {
+        let transform = Self::new_with_tolerance(a, b, c, d, 0.0)?;
+        Ok((transform, transform.condition_number()))
+    }
+
     /// Creates the identity transformation.
     pub fn identity() -> Self {
         Self::new(
@@ -76,19 +182,142 @@ impl MobiusTransform {
         ).expect("Identity transformation should always be valid")
     }
 
+    /// Creates the affine transformation f(z) = scale_rot · z + translation.
+    ///
+    /// `scale_rot` combines the scaling |scale_rot| and the rotation arg(scale_rot).
+    /// Affine maps are exactly the Möbius transformations with c = 0, which fix infinity.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InfiniteCoefficient` if either argument is infinite or NaN.
+    /// Returns `TransformError::SingularTransform` if `scale_rot` is zero.
+    pub fn from_affine(scale_rot: Complex64, translation: Complex64) -> Result<Self, TransformError> {
+        Self::new(
+            scale_rot,
+            translation,
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        )
+    }
+
+    /// Creates the translation f(z) = z + t.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InfiniteCoefficient` if `t` is infinite or NaN.
+    pub fn translation(t: Complex64) -> Result<Self, TransformError> {
+        Self::from_affine(Complex64::new(1.0, 0.0), t)
+    }
+
+    /// Creates the parabolic transformation with the given fixed point, conjugate to
+    /// the translation w ↦ w + `translation`.
+    ///
+    /// For a finite fixed point p the conjugating coordinate is w = 1/(z - p), which
+    /// sends p to infinity, giving
+    /// f(z) = ((1 + pt) z - p²t) / (tz + 1 - pt) with determinant one.
+    /// For p = ∞ no conjugation is needed and the result is the translation z ↦ z + t.
+    /// A zero translation gives the identity.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InfiniteCoefficient` if the translation or the fixed
+    /// point is NaN, or the coefficients overflow.
+    pub fn parabolic(fixed_point: Complex64, translation: Complex64) -> Result<Self, TransformError> {
+        if is_infinity(fixed_point) {
+            return Self::translation(translation);
+        }
+        let (p, t) = (fixed_point, translation);
+        let one = Complex64::new(1.0, 0.0);
+        Self::new(one + p * t, -p * p * t, t, one - p * t)
+    }
+
+    /// Creates the rotation f(z) = e^{iθ} z about the origin.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InfiniteCoefficient` if `angle` is infinite or NaN.
+    pub fn rotation(angle: f64) -> Result<Self, TransformError> {
+        Self::from_affine(Complex64::from_polar(1.0, angle), Complex64::new(0.0, 0.0))
+    }
+
+    /// Creates the elliptic transformation rotating the Riemann sphere by `angle` about
+    /// the given fixed point.
+    ///
+    /// This is the conjugate of z ↦ e^{iθ} z by the sphere rotation
+    /// g(z) = (z - p) / (p̄z + 1), which sends p to 0 and its antipode -1/p̄ to infinity.
+    /// Both are fixed, and the derivative at p is e^{iθ}. For p = ∞ the rotation is
+    /// about infinity, i.e. z ↦ e^{-iθ} z. An angle that is a multiple of 2π gives the
+    /// identity.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InfiniteCoefficient` if the angle or the fixed point is
+    /// NaN, or the coefficients overflow.
+    pub fn elliptic(fixed_point: Complex64, angle: f64) -> Result<Self, TransformError> {
+        if is_infinity(fixed_point) {
+            return Self::rotation(-angle);
+        }
+        let p = fixed_point;
+        let u = Complex64::from_polar(1.0, angle / 2.0);
+        let v = u.conj();
+        let p_sqr = p.norm_sqr();
+        Self::new(u + v * p_sqr, p * (v - u), p.conj() * (v - u), u * p_sqr + v)
+    }
+
+    /// Returns the affine form (scale_rot, translation) with f(z) = scale_rot · z + translation.
+    ///
+    /// Returns `None` when the normalized |c| is at least [`DEFAULT_TOLERANCE`] (as in
+    /// [`MobiusTransform::pole`]), i.e. when the transformation moves infinity and is
+    /// therefore not affine.
+    pub fn to_affine(&self) -> Option<(Complex64, Complex64)> {
+        if self.normalize().c.norm() >= DEFAULT_TOLERANCE {
+            return None;
+        }
+        // With c ≈ 0 the determinant is about ad, so d is non-zero
+        Some((self.a / self.d, self.b / self.d))
+    }
+
     /// Applies the transformation to a complex number.
     ///
     /// Properly handles the point at infinity according to the rules:
     /// - If z is infinity and c ≠ 0, a == 0: returns 0
     /// - If z is infinity and c == 0, a ≠ 0: returns infinity
     /// - If z is infinity and c ≠ 0, a ≠ 0: returns a/c
+    /// - If z is infinity and both |a| and |c| are below the tolerance: returns a/c
+    ///   computed exactly, or infinity when c is exactly zero
     /// - If denominator (cz + d) approaches zero: returns infinity
     /// - Otherwise: returns (az + b) / (cz + d)
+    ///
+    /// Intermediate overflow or underflow (for very large inputs, or very small
+    /// coefficients) never leaks NaN: the quotient is re-evaluated with rescaled
+    /// numerator and denominator, and falls back to infinity only when the result
+    /// genuinely leaves the range of `f64`. A NaN input is not a point of the extended
+    /// complex plane and produces NaN.
+    ///
+    /// Coefficients and denominators are treated as zero below [`DEFAULT_TOLERANCE`];
+    /// see [`MobiusTransform::apply_with_tolerance`] to configure it.
     pub fn apply(&self, z: Complex64) -> Complex64 {
+        self.apply_with_tolerance(z, DEFAULT_TOLERANCE)
+    }
+
+    /// Applies the transformation using a custom tolerance for zero checks.
+    ///
+    /// A point maps to infinity when |cz + d| < `tol`, and the coefficients a and c are
+    /// treated as zero below `tol` when applying the transformation to infinity.
+    /// A larger tolerance sends a wider neighbourhood of the pole -d/c to infinity,
+    /// which avoids huge but meaningless values near the pole. A smaller tolerance
+    /// resolves points closer to the pole as large finite values, which matters when
+    /// working with very small or very large magnitudes.
+    ///
+    /// Construction guarantees that a and c are not both zero, but a valid map with tiny
+    /// coefficients (e.g. from [`MobiusTransform::compose`] or
+    /// [`MobiusTransform::new_with_tolerance`]) may have both below `tol`. The tolerance
+    /// then cannot decide which of them vanishes, and the image of infinity is a/c
+    /// without any zero check: infinity when c is exactly zero, else the quotient.
+    pub fn apply_with_tolerance(&self, z: Complex64, tol: f64) -> Complex64 {
+        if z.is_nan() {
+            return Complex64::new(f64::NAN, f64::NAN);
+        }
+
         // Handle input infinity
         if is_infinity(z) {
-            let c_is_zero = self.c.norm() < 1e-10;
-            let a_is_zero = self.a.norm() < 1e-10;
+            let c_is_zero = self.c.norm() < tol;
+            let a_is_zero = self.a.norm() < tol;
             
             if c_is_zero && !a_is_zero {
                 // Case: c == 0 && a != 0 -> infinity
@@ -100,114 +329,1378 @@ impl MobiusTransform {
                 // Case: c != 0 && a != 0 -> a/c
                 return normalize_infinity(self.a / self.c);
             }
-            // Case: c == 0 && a == 0 should have been caught at construction
-            // (degenerate transform), but return infinity as fallback
-            return normalize_infinity(COMPLEX_INFINITY);
+            // Case: |a| and |c| both below tol. They cannot both be zero (a = c = 0 is
+            // singular and rejected at construction), so use the exact quotient
+            if self.c == Complex64::new(0.0, 0.0) {
+                return COMPLEX_INFINITY;
+            }
+            return normalize_infinity(self.a / self.c);
         }
         
         let numerator = self.a * z + self.b;
         let denominator = self.c * z + self.d;
         
-        if denominator.norm() < 1e-10 {
+        if denominator.norm() < tol {
             // Point maps to infinity
             normalize_infinity(COMPLEX_INFINITY)
         } else {
             let result = numerator / denominator;
+            if result.is_nan() {
+                // Intermediate overflow/underflow in the quotient
+                return self.apply_rescaled(z);
+            }
             normalize_infinity(result)
         }
     }
 
+    /// Evaluates (az + b) / (cz + d) for finite z while avoiding intermediate
+    /// overflow and underflow.
+    ///
+    /// For |z| > 1 numerator and denominator are divided by z, and both are then
+    /// scaled so their largest component is 1 before dividing. Returns infinity if
+    /// the quotient is still not representable.
+    fn apply_rescaled(&self, z: Complex64) -> Complex64 {
+        let (numerator, denominator) = if z.norm() > 1.0 {
+            let w = stable_reciprocal(z);
+            (self.a + self.b * w, self.c + self.d * w)
+        } else {
+            (self.a * z + self.b, self.c * z + self.d)
+        };
+
+        let scale = numerator.re.abs()
+            .max(numerator.im.abs())
+            .max(denominator.re.abs())
+            .max(denominator.im.abs());
+        if scale == 0.0 || !scale.is_finite() {
+            return COMPLEX_INFINITY;
+        }
+
+        let result = (numerator / scale) / (denominator / scale);
+        if result.is_nan() {
+            // Denominator vanished after rescaling: the point is the pole
+            return COMPLEX_INFINITY;
+        }
+        normalize_infinity(result)
+    }
+
+    /// Applies the transformation to any point type convertible to a complex number.
+    ///
+    /// Equivalent to `P::from_complex(self.apply(p.to_complex()))`; see
+    /// [`ComplexPoint`].
+    pub fn apply_point<P: ComplexPoint>(&self, p: P) -> P {
+        P::from_complex(self.apply(p.to_complex()))
+    }
+
+    /// Applies the transformation, returning `Err(AtInfinity)` instead of
+    /// [`COMPLEX_INFINITY`] when the image is the point at infinity.
+    ///
+    /// This lets numeric code branch on the point at infinity explicitly rather than
+    /// propagate infinite components into later arithmetic. Otherwise it behaves as
+    /// [`MobiusTransform::apply`]; in particular NaN input gives `Ok` with NaN.
+    pub fn try_apply(&self, z: Complex64) -> Result<Complex64, AtInfinity> {
+        let image = self.apply(z);
+        if is_infinity(image) {
+            Err(AtInfinity)
+        } else {
+            Ok(image)
+        }
+    }
+
+    /// Applies the transformation to homogeneous coordinates [z : w].
+    ///
+    /// Returns the matrix-vector product (az + bw, cz + dw) without dividing, so the
+    /// point of the extended plane it represents is (az + bw) / (cz + dw). The point
+    /// at infinity is [1 : 0], and its image has second coordinate c, so callers can
+    /// defer the division and detect infinity as a near-zero second coordinate. The
+    /// coordinates are only defined up to a common nonzero factor, which this
+    /// preserves: scaling the input or the coefficients scales the output.
+    pub fn apply_homogeneous(&self, z: Complex64, w: Complex64) -> (Complex64, Complex64) {
+        (self.a * z + self.b * w, self.c * z + self.d * w)
+    }
+
+    /// Returns a closure computing [`MobiusTransform::apply`], specialized for tight loops.
+    ///
+    /// The closure captures the coefficients and, for finite points away from the pole,
+    /// evaluates (az + b) / (cz + d) directly, comparing |cz + d|² against the squared
+    /// tolerance instead of computing a norm, and skipping the infinity and NaN
+    /// handling. Every other input (infinity, NaN, the pole, or an overflowing
+    /// quotient) falls back to `apply`, so the results are identical to `apply`.
+    /// This is the fast path for applying one transformation to many points.
+    pub fn compile(&self) -> impl Fn(Complex64) -> Complex64 {
+        let transform = *self;
+        let (a, b, c, d) = (self.a, self.b, self.c, self.d);
+        let tol_sqr = DEFAULT_TOLERANCE * DEFAULT_TOLERANCE;
+        move |z| {
+            if z.is_finite() {
+                let denominator = c * z + d;
+                if denominator.norm_sqr() >= tol_sqr {
+                    let result = (a * z + b) / denominator;
+                    if result.is_finite() {
+                        return result;
+                    }
+                }
+            }
+            transform.apply(z)
+        }
+    }
+
     /// Applies the transformation to a vector of complex numbers.
     pub fn apply_batch(&self, points: &Array1<Complex64>) -> Array1<Complex64> {
         points.mapv(|z| self.apply(z))
     }
 
+    /// Applies the transformation to a vector of complex numbers, also returning a
+    /// mask of the images that are the point at infinity.
+    ///
+    /// The mask is true exactly where the image is infinite, which happens at the pole
+    /// (see [`MobiusTransform::pole`]) and, for affine maps, at infinity itself. NaN
+    /// input is not flagged.
+    pub fn apply_batch_masked(&self, points: &Array1<Complex64>) -> (Array1<Complex64>, Vec<bool>) {
+        let images = self.apply_batch(points);
+        let mask = images.iter().map(|&w| is_infinity(w)).collect();
+        (images, mask)
+    }
+
+    /// Applies the inverse transformation to a vector of complex numbers.
+    ///
+    /// The inverse is computed once for the whole batch. As with
+    /// [`MobiusTransform::apply_batch`], the point at infinity is handled: it pulls
+    /// back to the pole of the inverse (a/c, the image of infinity), and the image of
+    /// infinity pulls back to [`COMPLEX_INFINITY`].
+    pub fn preimage_batch(&self, points: &Array1<Complex64>) -> Array1<Complex64> {
+        self.inverse().apply_batch(points)
+    }
+
+    /// Applies the transformation to a flat array of samples laid out as a grid.
+    ///
+    /// `points` holds the samples of a `rows` × `cols` grid in row-major order, as
+    /// produced by flattening an image; the images are returned in a 2D array of that
+    /// shape. Empty grids (with zero rows or columns) are allowed.
+    ///
+    /// # Errors
+    /// Returns `TransformError::ShapeMismatch` if `points` does not have exactly
+    /// `rows * cols` elements (or that product overflows).
+    pub fn apply_grid(
+        &self,
+        points: &Array1<Complex64>,
+        rows: usize,
+        cols: usize,
+    ) -> Result<Array2<Complex64>, TransformError> {
+        let mismatch = || TransformError::ShapeMismatch {
+            expected: vec![rows, cols],
+            found: points.shape().to_vec(),
+        };
+        if rows.checked_mul(cols) != Some(points.len()) {
+            return Err(mismatch());
+        }
+        self.apply_batch(points)
+            .into_shape_with_order((rows, cols))
+            .map_err(|_| mismatch())
+    }
+
+    /// Lazily applies the transformation to every point of an iterator.
+    ///
+    /// Equivalent to `iter.map(|z| transform.apply(z))`; nothing is allocated, so this
+    /// suits streaming pipelines. The returned iterator holds a copy of the
+    /// transformation and does not borrow it.
+    pub fn apply_iter<I: Iterator<Item = Complex64>>(&self, iter: I) -> impl Iterator<Item = Complex64> {
+        let transform = *self;
+        iter.map(move |z| transform.apply(z))
+    }
+
+    /// Returns the pullback z ↦ g(f(z)) of a function on the plane.
+    ///
+    /// Viewing g as a texture or coloring of the plane, the returned closure shows it
+    /// as seen through the transformation: its value at z is g at the image f(z), so
+    /// regions of the texture appear at their pre-images, as in
+    /// [`render_to_buffer`](crate::render::render_to_buffer). Points are mapped with
+    /// [`MobiusTransform::apply`], so g receives [`COMPLEX_INFINITY`] at the pole. The
+    /// closure holds a copy of the transformation and does not borrow it.
+    pub fn precompose<T>(&self, g: impl Fn(Complex64) -> T) -> impl Fn(Complex64) -> T {
+        let transform = *self;
+        move |z| g(transform.apply(z))
+    }
+
+    /// Applies the transformation to a polyline, subdividing each edge so the curved
+    /// image of the straight edges is approximated.
+    ///
+    /// Each edge is split into `subdivisions + 1` equal pieces, the `subdivisions`
+    /// interior points are inserted between its endpoints, and every point is then
+    /// mapped. With `subdivisions = 0` only the vertices are mapped. For n vertices the
+    /// result has n + (n - 1) · `subdivisions` points; passing a closing vertex equal
+    /// to the first one closes a polygon.
+    ///
+    /// An edge with an endpoint at infinity is a ray or line that cannot be sampled
+    /// uniformly, so no interior points are inserted for it.
+    pub fn apply_polyline(&self, points: &[Complex64], subdivisions: usize) -> Vec<Complex64> {
+        let mut result = Vec::with_capacity(points.len() + points.len().saturating_sub(1) * subdivisions);
+
+        for (i, &start) in points.iter().enumerate() {
+            result.push(self.apply(start));
+
+            let Some(&end) = points.get(i + 1) else { break };
+            if is_infinity(start) || is_infinity(end) {
+                continue;
+            }
+            for k in 1..=subdivisions {
+                let t = k as f64 / (subdivisions + 1) as f64;
+                result.push(self.apply(start + (end - start) * t));
+            }
+        }
+
+        result
+    }
+
+    /// Maps the boundary of an axis-aligned rectangle, returning its image as a closed
+    /// polyline.
+    ///
+    /// The rectangle has opposite corners `min` and `max`; their components are sorted,
+    /// so any two opposite corners describe the same rectangle. The boundary is traversed
+    /// counterclockwise from the lower-left corner and passed to
+    /// [`MobiusTransform::apply_polyline`] with `subdivisions` points per edge, so the
+    /// result has 5 + 4 · `subdivisions` points, the images of the corners are at
+    /// multiples of `subdivisions + 1`, and the last point repeats the first. An edge
+    /// through the pole has an image passing through infinity.
+    pub fn apply_rect(&self, min: Complex64, max: Complex64, subdivisions: usize) -> Vec<Complex64> {
+        let (x_min, x_max) = (min.re.min(max.re), min.re.max(max.re));
+        let (y_min, y_max) = (min.im.min(max.im), min.im.max(max.im));
+        let corners = [
+            Complex64::new(x_min, y_min),
+            Complex64::new(x_max, y_min),
+            Complex64::new(x_max, y_max),
+            Complex64::new(x_min, y_max),
+            Complex64::new(x_min, y_min),
+        ];
+        self.apply_polyline(&corners, subdivisions)
+    }
+
+    /// Returns the complex derivative f'(z) = (ad - bc) / (cz + d)².
+    ///
+    /// Computed from the normalized coefficients, so that f'(z) = 1 / (cz + d)². At the
+    /// pole -d/c (denominator below [`DEFAULT_TOLERANCE`]) the derivative is
+    /// infinite and [`COMPLEX_INFINITY`] is returned. At z = ∞ the limit of f'(z) is
+    /// returned: 0 when c ≠ 0, and the constant slope a/d of an affine map otherwise.
+    /// NaN input produces NaN.
+    pub fn derivative(&self, z: Complex64) -> Complex64 {
+        if z.is_nan() {
+            return Complex64::new(f64::NAN, f64::NAN);
+        }
+        let n = self.normalized_matrix();
+        let (c, d) = (n[1][0], n[1][1]);
+
+        if is_infinity(z) {
+            if c.norm() < DEFAULT_TOLERANCE {
+                return d.inv() * d.inv();
+            }
+            return Complex64::new(0.0, 0.0);
+        }
+
+        let denominator = c * z + d;
+        if denominator.norm() < DEFAULT_TOLERANCE {
+            return COMPLEX_INFINITY;
+        }
+        normalize_infinity(denominator.inv() * denominator.inv())
+    }
+
+    /// Pushes a tangent vector `v` at `z` forward, returning f'(z) · v.
+    ///
+    /// The result is the tangent vector at f(z): it is rotated by arg f'(z) and scaled
+    /// by |f'(z)|. At the pole the derivative is infinite and [`COMPLEX_INFINITY`] is
+    /// returned for any non-zero vector. The zero vector always maps to zero. See
+    /// [`MobiusTransform::derivative`] for the behavior at z = ∞.
+    pub fn pushforward(&self, z: Complex64, v: Complex64) -> Complex64 {
+        if v == Complex64::new(0.0, 0.0) {
+            return v;
+        }
+        let slope = self.derivative(z);
+        if is_infinity(slope) {
+            return COMPLEX_INFINITY;
+        }
+        normalize_infinity(slope * v)
+    }
+
+    /// Returns the image of an infinitesimal circle around `z` as
+    /// (center, radius, rotation).
+    ///
+    /// To first order the map sends the circle of the given radius around z to the
+    /// circle around f(z) with radius |f'(z)| · `radius`, rotated by arg f'(z); this is
+    /// what makes the map conformal. The approximation is good for radii much smaller
+    /// than the distance from z to the pole.
+    ///
+    /// The values follow [`MobiusTransform::apply`] and [`MobiusTransform::derivative`]:
+    /// at the pole the radius is infinite, and the rotation is reported as 0 there
+    /// since it is undefined.
+    pub fn infinitesimal_image(&self, z: Complex64, radius: f64) -> (Complex64, f64, f64) {
+        let derivative = self.derivative(z);
+        let rotation = if is_infinity(derivative) { 0.0 } else { derivative.arg() };
+        (self.apply(z), derivative.norm() * radius, rotation)
+    }
+
+    /// Returns the Schwarzian derivative S(f)(z) = f'''/f' - (3/2)(f''/f')².
+    ///
+    /// The Schwarzian of every Möbius transformation vanishes identically, so this is
+    /// useful as a numerical self-check: the result is zero up to rounding. It is
+    /// evaluated from the explicit derivatives f' = 1/w², f'' = -2c/w³ and
+    /// f''' = 6c²/w⁴, with w = cz + d for the normalized coefficients. At the pole and
+    /// at infinity, where these are not finite, the analytic value 0 is returned.
+    pub fn schwarzian(&self, z: Complex64) -> Complex64 {
+        let zero = Complex64::new(0.0, 0.0);
+        if is_infinity(z) {
+            return zero;
+        }
+        let n = self.normalized_matrix();
+        let (c, d) = (n[1][0], n[1][1]);
+        let denominator = c * z + d;
+        if denominator.norm() < DEFAULT_TOLERANCE {
+            return zero;
+        }
+
+        let w = denominator.inv();
+        let first = w * w;
+        let second = -2.0 * c * w * w * w;
+        let third = 6.0 * c * c * w * w * w * w;
+        third / first - 1.5 * (second / first) * (second / first)
+    }
+
+    /// Returns the Taylor coefficients c_0, ..., c_order of f(z0 + h) = Σ c_k h^k.
+    ///
+    /// With the coefficients normalized so that ad - bc = 1 and u = c·z0 + d,
+    /// f(z) = a/c - 1 / (c(cz + d)) expands as a geometric series, giving c_0 = f(z0)
+    /// and c_k = (-1)^(k+1) c^(k-1) / u^(k+1) for k >= 1. In particular c_1 = f'(z0)
+    /// and, for affine maps (c = 0), every coefficient past c_1 vanishes. The series
+    /// converges for |h| < |z0 + d/c|, the distance to the pole.
+    ///
+    /// The expansion is invalid at the pole -d/c (denominator below
+    /// [`DEFAULT_TOLERANCE`]), where f has a simple pole rather than a power series,
+    /// and at z0 = ∞ or NaN; all coefficients are then NaN.
+    pub fn taylor(&self, z0: Complex64, order: usize) -> Vec<Complex64> {
+        let nan = Complex64::new(f64::NAN, f64::NAN);
+        let n = self.normalized_matrix();
+        let (c, d) = (n[1][0], n[1][1]);
+        let denominator = c * z0 + d;
+        if !z0.is_finite() || denominator.norm() < DEFAULT_TOLERANCE {
+            return vec![nan; order + 1];
+        }
+
+        let mut coefficients = Vec::with_capacity(order + 1);
+        coefficients.push(self.apply(z0));
+        let ratio = -c / denominator;
+        let mut term = denominator.inv() * denominator.inv();
+        for _ in 1..=order {
+            coefficients.push(term);
+            term *= ratio;
+        }
+        coefficients
+    }
+
+    /// Returns the partial derivatives (∂f/∂a, ∂f/∂b, ∂f/∂c, ∂f/∂d) of f(z) with
+    /// respect to the stored coefficients.
+    ///
+    /// By the quotient rule, with u = cz + d,
+    /// ∂f/∂a = z/u, ∂f/∂b = 1/u, ∂f/∂c = -z f(z)/u and ∂f/∂d = -f(z)/u. To first
+    /// order, perturbing the coefficients by (δa, δb, δc, δd) changes f(z) by the sum
+    /// of the products, which propagates coefficient uncertainty to the image. The
+    /// coefficients are only defined up to scale, so the gradients refer to the
+    /// stored coefficients (for a map built with [`MobiusTransform::new`], the ones
+    /// passed to it), not to a normalized form: scaling all of them by k divides the
+    /// gradients by k.
+    ///
+    /// At z = ∞, f = a/c and the gradients are (1/c, 0, -a/c², 0). The pole (|u| below
+    /// [`DEFAULT_TOLERANCE`], as in [`MobiusTransform::apply`]), infinity when c is
+    /// below the tolerance, and NaN input give NaN in every component.
+    pub fn coefficient_gradients(&self, z: Complex64) -> [Complex64; 4] {
+        let nan = Complex64::new(f64::NAN, f64::NAN);
+        let zero = Complex64::new(0.0, 0.0);
+        if z.is_nan() {
+            return [nan; 4];
+        }
+        if is_infinity(z) {
+            if self.c.norm() < DEFAULT_TOLERANCE {
+                return [nan; 4];
+            }
+            let inverse_c = self.c.inv();
+            return [inverse_c, zero, -self.a * inverse_c * inverse_c, zero];
+        }
+
+        let denominator = self.c * z + self.d;
+        if denominator.norm() < DEFAULT_TOLERANCE {
+            return [nan; 4];
+        }
+        let inverse = denominator.inv();
+        let image = (self.a * z + self.b) * inverse;
+        [z * inverse, inverse, -z * image * inverse, -image * inverse]
+    }
+
     /// Returns the matrix representation of the transformation.
     pub fn to_matrix(&self) -> Array2<Complex64> {
         Array2::from_shape_vec((2, 2), vec![self.a, self.b, self.c, self.d])
             .expect("Valid 2x2 matrix")
     }
 
+    /// Creates a transformation from its matrix representation [[a, b], [c, d]].
+    ///
+    /// This is the inverse of [`MobiusTransform::to_matrix`]; the round trip is exact.
+    ///
+    /// # Errors
+    /// Returns `TransformError::ShapeMismatch` if the matrix is not 2×2.
+    /// Returns `TransformError::InfiniteCoefficient` if any entry is infinite or NaN.
+    /// Returns `TransformError::SingularTransform` if the determinant is zero.
+    pub fn from_matrix(m: &Array2<Complex64>) -> Result<Self, TransformError> {
+        if m.shape() != [2, 2] {
+            return Err(TransformError::ShapeMismatch {
+                expected: vec![2, 2],
+                found: m.shape().to_vec(),
+            });
+        }
+        Self::new(m[[0, 0]], m[[0, 1]], m[[1, 0]], m[[1, 1]])
+    }
+
+    /// Returns the coefficients as plain floats, `[a.re, a.im, b.re, b.im, c.re, c.im, d.re, d.im]`.
+    ///
+    /// This is the simplest interchange format, e.g. across an FFI boundary; the
+    /// coefficients are the stored ones, without normalization.
+    pub fn to_array(&self) -> [f64; 8] {
+        [
+            self.a.re, self.a.im, self.b.re, self.b.im,
+            self.c.re, self.c.im, self.d.re, self.d.im,
+        ]
+    }
+
+    /// Creates a transformation from the layout of [`MobiusTransform::to_array`].
+    ///
+    /// The coefficients are validated as in [`MobiusTransform::new`], and the round
+    /// trip is exact.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InfiniteCoefficient` if any entry is infinite or NaN.
+    /// Returns `TransformError::SingularTransform` if the determinant is zero.
+    pub fn from_array(values: [f64; 8]) -> Result<Self, TransformError> {
+        let [a_re, a_im, b_re, b_im, c_re, c_im, d_re, d_im] = values;
+        Self::new(
+            Complex64::new(a_re, a_im),
+            Complex64::new(b_re, b_im),
+            Complex64::new(c_re, c_im),
+            Complex64::new(d_re, d_im),
+        )
+    }
+
+    /// Returns a compact, stable text form of the coefficients.
+    ///
+    /// The format is `a=<re><sign><im>i;b=...;c=...;d=...`, for example
+    /// `a=2+1i;b=1+0i;c=1+1i;d=3+0i`. Components are written with the shortest
+    /// representation that parses back to the same `f64`, so
+    /// [`MobiusTransform::from_coeff_string`] reproduces the coefficients exactly.
+    pub fn to_coeff_string(&self) -> String {
+        let format = |z: Complex64| {
+            let sign = if z.im.is_sign_negative() { '-' } else { '+' };
+            format!("{}{}{}i", z.re, sign, z.im.abs())
+        };
+        format!(
+            "a={};b={};c={};d={}",
+            format(self.a),
+            format(self.b),
+            format(self.c),
+            format(self.d)
+        )
+    }
+
+    /// Parses a transformation from the format produced by [`MobiusTransform::to_coeff_string`].
+    ///
+    /// Whitespace around entries is ignored and the entries may appear in any order,
+    /// but each of a, b, c and d must appear exactly once. The parsed coefficients are
+    /// validated as in [`MobiusTransform::new`].
+    ///
+    /// # Errors
+    /// Returns `TransformError::InvalidFormat` if the string is malformed.
+    /// Returns `TransformError::InfiniteCoefficient` or `TransformError::SingularTransform`
+    /// if the parsed coefficients do not form a valid transformation.
+    pub fn from_coeff_string(s: &str) -> Result<Self, TransformError> {
+        let mut coefficients: [Option<Complex64>; 4] = [None; 4];
+
+        for entry in s.trim().split(';') {
+            let (name, value) = entry.split_once('=').ok_or_else(|| {
+                TransformError::InvalidFormat(format!("expected `name=value`, found `{}`", entry.trim()))
+            })?;
+            let index = match name.trim() {
+                "a" => 0,
+                "b" => 1,
+                "c" => 2,
+                "d" => 3,
+                other => {
+                    return Err(TransformError::InvalidFormat(format!("unknown coefficient `{}`", other)));
+                }
+            };
+            if coefficients[index].is_some() {
+                return Err(TransformError::InvalidFormat(format!("duplicate coefficient `{}`", name.trim())));
+            }
+            let value = parse_complex(value.trim()).ok_or_else(|| {
+                TransformError::InvalidFormat(format!("invalid complex number `{}`", value.trim()))
+            })?;
+            coefficients[index] = Some(value);
+        }
+
+        match coefficients {
+            [Some(a), Some(b), Some(c), Some(d)] => Self::new(a, b, c, d),
+            _ => Err(TransformError::InvalidFormat("expected coefficients a, b, c and d".to_string())),
+        }
+    }
+
     /// Composes this transformation with another: (self ∘ other)(z) = self(other(z))
     ///
     /// The composition of two valid Möbius transformations is always a valid
-    /// Möbius transformation, so this operation cannot fail.
+    /// Möbius transformation, so this operation cannot fail. The determinant of the
+    /// product is the product of the determinants, which may be far below
+    /// [`DEFAULT_TOLERANCE`] (e.g. for f⁻¹ ∘ f with a nearly singular f), so the
+    /// absolute cutoff of [`MobiusTransform::new`] is not applied. Both operands are
+    /// rescaled by powers of two (which is exact) so that their largest coefficient is
+    /// of order one, keeping the product clear of overflow and underflow.
     pub fn compose(&self, other: &MobiusTransform) -> MobiusTransform {
+        let (f, g) = (self.rescaled(), other.rescaled());
         // Mathematical guarantee: composition of valid transforms is valid
-        MobiusTransform::new(
-            self.a * other.a + self.b * other.c,
-            self.a * other.b + self.b * other.d,
-            self.c * other.a + self.d * other.c,
-            self.c * other.b + self.d * other.d,
+        MobiusTransform::new_with_tolerance(
+            f.a * g.a + f.b * g.c,
+            f.a * g.b + f.b * g.d,
+            f.c * g.a + f.d * g.c,
+            f.c * g.b + f.d * g.d,
+            0.0,
         ).expect("Composition of valid transforms should always be valid")
     }
 
-    /// Returns the inverse transformation.
+    /// Returns the same map with coefficients scaled by a power of two so that the
+    /// largest component lies in [1, 2).
+    fn rescaled(&self) -> MobiusTransform {
+        let largest = [self.a, self.b, self.c, self.d]
+            .iter()
+            .map(|z| z.re.abs().max(z.im.abs()))
+            .fold(0.0, f64::max);
+        let scale = 2.0_f64.powi(-(largest.log2().floor() as i32));
+        MobiusTransform {
+            a: self.a * scale,
+            b: self.b * scale,
+            c: self.c * scale,
+            d: self.d * scale,
+        }
+    }
+
+    /// Composes a list of transformations: `compose_all(&[f, g, h])` is f ∘ g ∘ h.
     ///
-    /// Since the determinant is guaranteed to be non-zero (checked at creation),
-    /// the inverse always exists.
-    pub fn inverse(&self) -> MobiusTransform {
-        let det = self.a * self.d - self.b * self.c;
-        MobiusTransform::new(
-            self.d / det,
-            -self.b / det,
-            -self.c / det,
-            self.a / det,
-        ).expect("Inverse of a valid transform should always be valid")
+    /// This matches function composition order, so the last element is applied first:
+    /// the result maps z to f(g(h(z))). An empty slice gives the identity.
+    pub fn compose_all(transforms: &[MobiusTransform]) -> MobiusTransform {
+        transforms
+            .iter()
+            .fold(MobiusTransform::identity(), |acc, transform| acc.compose(transform))
     }
 
-    /// Returns the determinant ad - bc.
-    pub fn determinant(&self) -> Complex64 {
-        self.a * self.d - self.b * self.c
+    /// Returns the transformation followed by the scaling w ↦ kw, i.e. z ↦ k · f(z).
+    ///
+    /// The factor k = `factor` scales by |k| and rotates by arg k, as in
+    /// [`MobiusTransform::from_affine`]. This is the composition (w ↦ kw) ∘ self, e.g. for
+    /// adjusting the output of a map before domain coloring. Infinity stays infinity.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InfiniteCoefficient` if the factor is infinite or NaN.
+    /// Returns `TransformError::SingularTransform` if the factor is zero, since z ↦ 0 is
+    /// not a Möbius transformation.
+    pub fn then_scale(&self, factor: Complex64) -> Result<MobiusTransform, TransformError> {
+        Ok(MobiusTransform::from_affine(factor, Complex64::new(0.0, 0.0))?.compose(self))
     }
 
-    /// Normalizes the transformation so that ad - bc = 1.
+    /// Returns the transformation preceded by the scaling z ↦ kz, i.e. z ↦ f(kz).
     ///
-    /// Since the determinant is guaranteed to be non-zero, the normalization
-    /// produces a valid transformation.
-    pub fn normalize(&self) -> MobiusTransform {
-        let det_sqrt = self.determinant().sqrt();
-        MobiusTransform::new(
-            self.a / det_sqrt,
-            self.b / det_sqrt,
-            self.c / det_sqrt,
-            self.d / det_sqrt,
-        ).expect("Normalization of a valid transform should always be valid")
+    /// This is the composition self ∘ (z ↦ kz); see [`MobiusTransform::then_scale`].
+    ///
+    /// # Errors
+    /// Returns `TransformError::InfiniteCoefficient` if the factor is infinite or NaN.
+    /// Returns `TransformError::SingularTransform` if the factor is zero.
+    pub fn pre_scale(&self, factor: Complex64) -> Result<MobiusTransform, TransformError> {
+        Ok(self.compose(&MobiusTransform::from_affine(factor, Complex64::new(0.0, 0.0))?))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Tests whether two transformations are the same map, using [`DEFAULT_TOLERANCE`].
+    ///
+    /// See [`MobiusTransform::approx_eq_with_tolerance`].
+    pub fn approx_eq(&self, other: &MobiusTransform) -> bool {
+        self.approx_eq_with_tolerance(other, DEFAULT_TOLERANCE)
+    }
 
-    #[test]
-    fn test_identity() {
-        let id = MobiusTransform::identity();
-        let z = Complex64::new(3.0, 4.0);
-        let result = id.apply(z);
-        assert!((result - z).norm() < 1e-10);
+    /// Tests whether two transformations are the same map, up to a tolerance.
+    ///
+    /// Coefficients are only defined up to a common non-zero scalar, so both
+    /// transformations are normalized to ad - bc = 1 first. The normalized matrices
+    /// are then equal up to sign, and are compared entrywise: the transformations are
+    /// approximately equal when the Frobenius norm of A - B or of A + B is at most
+    /// `tol` · max(1, ‖A‖).
+    pub fn approx_eq_with_tolerance(&self, other: &MobiusTransform, tol: f64) -> bool {
+        let lhs = self.normalize();
+        let rhs = other.normalize();
+        let lhs = [lhs.a, lhs.b, lhs.c, lhs.d];
+        let rhs = [rhs.a, rhs.b, rhs.c, rhs.d];
+
+        let frobenius = |coefficients: [Complex64; 4]| -> f64 {
+            coefficients.iter().map(|z| z.norm_sqr()).sum::<f64>().sqrt()
+        };
+        let difference = frobenius(std::array::from_fn(|i| lhs[i] - rhs[i]));
+        let sum = frobenius(std::array::from_fn(|i| lhs[i] + rhs[i]));
+
+        difference.min(sum) <= tol * frobenius(lhs).max(1.0)
     }
-    
-    #[test]
-    fn test_identity_at_infinity() {
-        let id = MobiusTransform::identity();
-        let result = id.apply(COMPLEX_INFINITY);
-        assert!(is_infinity(result));
+
+    /// Tests whether the transformation is the identity map, up to a tolerance.
+    ///
+    /// Coefficients are only defined up to a common non-zero scalar, so (5, 0, 0, 5) is
+    /// the identity even though it differs from [`MobiusTransform::identity`] entrywise,
+    /// as do the results of round trips such as f⁻¹ ∘ f (whose coefficients are
+    /// det(f) · I). With the normalized matrix this checks b ≈ 0, c ≈ 0 and a ≈ d ≈ ±1;
+    /// it is [`MobiusTransform::approx_eq_with_tolerance`] against the identity.
+    pub fn is_identity(&self, tol: f64) -> bool {
+        self.approx_eq_with_tolerance(&MobiusTransform::identity(), tol)
     }
 
-    #[test]
-    fn test_composition() {
-        let m1 = MobiusTransform::new(
-            Complex64::new(2.0, 0.0),
-            Complex64::new(1.0, 0.0),
-            Complex64::new(0.0, 0.0),
-            Complex64::new(1.0, 0.0),
-        ).unwrap();
-        let m2 = MobiusTransform::new(
-            Complex64::new(1.0, 0.0),
-            Complex64::new(1.0, 0.0),
-            Complex64::new(0.0, 0.0),
-            Complex64::new(1.0, 0.0),
+    /// Tests whether this transformation commutes with another, up to a tolerance.
+    ///
+    /// Compares self ∘ other with other ∘ self using
+    /// [`MobiusTransform::approx_eq_with_tolerance`]. Commuting pairs generate
+    /// abelian subgroups; see [`MobiusTransform::commutator`] for when this happens.
+    pub fn commutes_with(&self, other: &MobiusTransform, tol: f64) -> bool {
+        self.compose(other)
+            .approx_eq_with_tolerance(&other.compose(self), tol)
+    }
+
+    /// Returns the commutator [self, other] = self ∘ other ∘ self⁻¹ ∘ other⁻¹.
+    ///
+    /// The commutator is the identity exactly when the two transformations commute.
+    /// Two non-identity transformations commute if and only if they have the same
+    /// fixed points, or both are involutions (f ∘ f = id) each of which swaps the
+    /// fixed points of the other, as z ↦ -z and z ↦ 1/z do.
+    pub fn commutator(&self, other: &MobiusTransform) -> MobiusTransform {
+        self.compose(other)
+            .compose(&self.inverse())
+            .compose(&other.inverse())
+    }
+
+    /// Returns the inverse transformation.
+    ///
+    /// The inverse is represented by the adjugate [[d, -b], [-c, a]]: since a
+    /// transformation does not change when its coefficients are scaled, no division by
+    /// the determinant is needed. The coefficients of the inverse are therefore exact,
+    /// its determinant equals that of self, and it is valid whenever self is. The
+    /// composition inverse ∘ self is det · I up to one rounding per entry, so it is
+    /// the identity within relative error about machine epsilon times the condition
+    /// number (see [`MobiusTransform::condition_number`]), however small or large the
+    /// determinant is.
+    pub fn inverse(&self) -> MobiusTransform {
+        // The determinant is that of self, so only a zero check applies; `new` would
+        // reject maps with a small determinant built by `new_checked` or `compose`
+        let inverse = MobiusTransform::new_with_tolerance(self.d, -self.b, -self.c, self.a, 0.0)
+            .expect("Inverse of a valid transform should always be valid");
+        debug_assert!(
+            inverse.same_class_as(self),
+            "Inverse of a {:?} transform classified as {:?}",
+            self.classify(),
+            inverse.classify()
+        );
+        inverse
+    }
+
+    /// Returns a square root g of the transformation, so that g ∘ g = self.
+    ///
+    /// Every non-identity Möbius transformation has several square roots (for example
+    /// both rotations by θ/2 and by θ/2 + π square to the rotation by θ), so this
+    /// returns the principal one: with the normalized matrix N (det N = 1) signed so
+    /// that Re(tr N) >= 0, the root is (N + I) / √(tr N + 2). Its multiplier is the
+    /// principal square root of the multiplier of self, so a rotation by θ ∈ (-π, π]
+    /// has the rotation by θ/2 as its square root.
+    ///
+    /// This closed form comes from the Cayley–Hamilton theorem and agrees with the
+    /// square root taken through the eigendecomposition, but it also covers the
+    /// parabolic case where N is not diagonalizable: the square root of a
+    /// translation by t is the translation by t/2. The sign choice keeps
+    /// |tr N + 2| >= 2, so the division is always well defined.
+    pub fn sqrt(&self) -> MobiusTransform {
+        let n = sl2::with_positive_trace(&self.normalized_matrix());
+        let one = Complex64::new(1.0, 0.0);
+        let scale = (n[0][0] + n[1][1] + 2.0).sqrt();
+        MobiusTransform::new(
+            (n[0][0] + one) / scale,
+            n[0][1] / scale,
+            n[1][0] / scale,
+            (n[1][1] + one) / scale,
+        ).expect("Square root of a valid transform should always be valid")
+    }
+
+    /// Returns a distance between two transformations as elements of PSL(2,C).
+    ///
+    /// The distance is the Frobenius norm of the principal matrix logarithm of the
+    /// normalized matrix of other ∘ self⁻¹, i.e. the size of the smallest
+    /// infinitesimal generator carrying self to other. It is zero if and only if the
+    /// two are the same map (up to scalar), and it is symmetric because the
+    /// logarithm of an inverse is the negated logarithm.
+    ///
+    /// It is right-invariant, d(f ∘ h, g ∘ h) = d(f, g) for every h, and also
+    /// left-invariant when h is a rotation of the sphere (an element of PSU(2)).
+    /// It is not the geodesic distance of any Riemannian metric, and the triangle
+    /// inequality is not guaranteed, so it is best used for comparing how far apart
+    /// nearby transformations are, e.g. for animation easing.
+    pub fn distance(&self, other: &MobiusTransform) -> f64 {
+        let relative = other.compose(&self.inverse());
+        let generator = sl2::log(&sl2::with_positive_trace(&relative.normalized_matrix()));
+        sl2::frobenius_norm(&generator)
+    }
+
+    /// Interpolates between two transformations along a one-parameter subgroup.
+    ///
+    /// Returns exp(t · X) ∘ self, where X is the principal logarithm of the normalized
+    /// matrix of other ∘ self⁻¹ (see [`MobiusTransform::distance`]). This gives self at
+    /// t = 0 and other at t = 1, moving at constant speed in between, and every
+    /// intermediate map is a genuine Möbius transformation. Values of t outside [0, 1]
+    /// extrapolate along the same path.
+    ///
+    /// When other ∘ self⁻¹ is a half-turn (tr = 0) the two rotation directions are
+    /// equally short and the principal branch picks one of them.
+    ///
+    /// When other ∘ self⁻¹ is parabolic its matrix is not diagonalizable, and near
+    /// tr = ±2 the logarithm is ill-conditioned. That case is handled separately by
+    /// conjugating to a translation w ↦ w + τ, which interpolates to w ↦ w + tτ.
+    ///
+    /// # Errors
+    /// Returns the errors of [`MobiusTransform::flow`] for the step exp(t · X):
+    /// `TransformError::InfiniteCoefficient` if t is infinite or NaN, and
+    /// `TransformError::SingularTransform` if its determinant rounds to zero for large |t|.
+    pub fn interpolate(&self, other: &MobiusTransform, t: f64) -> Result<MobiusTransform, TransformError> {
+        let relative = other.compose(&self.inverse());
+        if relative.classify() == TransformClass::Parabolic {
+            if let Some(step) = relative.parabolic_fraction(t) {
+                return Ok(step.compose(self));
+            }
+        }
+        let generator = sl2::log(&sl2::with_positive_trace(&relative.normalized_matrix()));
+        Ok(exp_transform(&generator, t)?.compose(self))
+    }
+
+    /// Interpolates between two transformations, resolving the branch of the
+    /// logarithm by the distance points actually travel.
+    ///
+    /// The logarithm of other ∘ self⁻¹ has one branch for each representative ±N of
+    /// its normalized matrix. If tr N = 2 cosh θ, the multiplier turns by the angle
+    /// 2 Im θ along the path, and the two branches turn by angles differing by 2π.
+    /// [`MobiusTransform::interpolate`] always takes the branch with Re(tr N) >= 0,
+    /// whose rotation |2 Im θ| is at most π. Near a half-turn (rotation close to π)
+    /// the two branches rotate equally far in opposite directions, and the sign of
+    /// Re(tr N), hence the direction, is decided by rounding. Points far from the
+    /// fixed points can then sweep almost all the way around the sphere.
+    ///
+    /// The heuristic used here picks the branch with the smaller total rotation, and
+    /// when the rotations agree to within [`DEFAULT_TOLERANCE`] it breaks the tie
+    /// by the summed chordal length of the paths of the images under self of 0, ±1,
+    /// ±i and ∞ (sampled at 32 steps). Away from half-turns and for parabolic maps the
+    /// result is exactly [`MobiusTransform::interpolate`]. The choice depends only on
+    /// self and other, not on t, so successive frames of an animation stay on one
+    /// path.
+    ///
+    /// # Errors
+    /// Returns the errors of [`MobiusTransform::flow`] for the step exp(t · X):
+    /// `TransformError::InfiniteCoefficient` if t is infinite or NaN, and
+    /// `TransformError::SingularTransform` if its determinant rounds to zero for large |t|.
+    pub fn interpolate_shortest(&self, other: &MobiusTransform, t: f64) -> Result<MobiusTransform, TransformError> {
+        let relative = other.compose(&self.inverse());
+        if relative.classify() == TransformClass::Parabolic {
+            return self.interpolate(other, t);
+        }
+        let n = sl2::with_positive_trace(&relative.normalized_matrix());
+        let flipped = sl2::scale(&n, Complex64::new(-1.0, 0.0));
+        let rotation = |m: &Matrix2| 2.0 * ((m[0][0] + m[1][1]) / 2.0).acosh().im.abs();
+
+        let principal = sl2::log(&n);
+        let generator = if (rotation(&n) - rotation(&flipped)).abs() < DEFAULT_TOLERANCE {
+            let alternative = sl2::log(&flipped);
+            let probes = [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(1.0, 0.0),
+                Complex64::new(-1.0, 0.0),
+                Complex64::new(0.0, 1.0),
+                Complex64::new(0.0, -1.0),
+                COMPLEX_INFINITY,
+            ].map(|z| self.apply(z));
+            if path_length(&alternative, &probes)? < path_length(&principal, &probes)? {
+                alternative
+            } else {
+                principal
+            }
+        } else {
+            principal
+        };
+        Ok(exp_transform(&generator, t)?.compose(self))
+    }
+
+    /// Returns the fraction f_t of a parabolic transformation.
+    ///
+    /// With w = 1/(z - p) for the fixed point p (or w = z when p = ∞), the map is
+    /// conjugate to w ↦ w + τ, and f_t is conjugate to w ↦ w + tτ.
+    fn parabolic_fraction(&self, t: f64) -> Option<MobiusTransform> {
+        let fixed_point = *self.fixed_points().first()?;
+        let g = if is_infinity(fixed_point) {
+            MobiusTransform::identity()
+        } else {
+            let one = Complex64::new(1.0, 0.0);
+            MobiusTransform::new(Complex64::new(0.0, 0.0), one, one, -fixed_point).ok()?
+        };
+        let (_, b, _, d) = g.compose(self).compose(&g.inverse()).coefficients();
+        MobiusTransform::parabolic(fixed_point, b / d * t).ok()
+    }
+
+    /// Returns the fraction f_t of the transformation, with f_0 the identity and f_1 = self.
+    ///
+    /// Equivalent to `MobiusTransform::identity().interpolate(self, t)`. The maps f_t
+    /// form a one-parameter group, f_s ∘ f_t = f_{s+t}, so f_{1/2} is the principal
+    /// square root (see [`MobiusTransform::sqrt`]). Values of t outside [0, 1]
+    /// extrapolate: f_2 = self ∘ self and f_{-1} = self⁻¹.
+    ///
+    /// # Errors
+    /// Returns the errors of [`MobiusTransform::interpolate`] for large or non-finite t.
+    pub fn fraction(&self, t: f64) -> Result<MobiusTransform, TransformError> {
+        MobiusTransform::identity().interpolate(self, t)
+    }
+
+    /// Returns the mean of several transformations.
+    ///
+    /// This is the exponential barycenter (the group analogue of the Fréchet mean): the
+    /// map B for which the principal logarithms X_i of the normalized matrices of
+    /// T_i ∘ B⁻¹ average to zero. Starting from the first transformation, each
+    /// iteration replaces B by exp(X̄) ∘ B, where X̄ is the average of the current
+    /// logarithms; this is the fixed-point iteration of Pennec and Arsigny. The
+    /// iteration has converged when the Frobenius norm of X̄ drops below 10⁻¹²,
+    /// which is typically reached in a handful of iterations for maps within a
+    /// moderate [`MobiusTransform::distance`] of each other.
+    ///
+    /// The mean of a single map is that map, and the mean of two is the midpoint of
+    /// [`MobiusTransform::interpolate`]. Like the distance, the mean is
+    /// right-invariant: mean(T_i ∘ h) = mean(T_i) ∘ h. It is also invariant under
+    /// reordering the inputs. When some T_i ∘ B⁻¹ is a half-turn the principal
+    /// logarithm is ambiguous (see [`MobiusTransform::interpolate_shortest`]), and the
+    /// mean of widely spread maps need not be unique.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InvalidArgument` if `transforms` is empty or the
+    /// iteration does not converge within 100 iterations.
+    pub fn mean(transforms: &[MobiusTransform]) -> Result<MobiusTransform, TransformError> {
+        let Some(first) = transforms.first() else {
+            return Err(TransformError::InvalidArgument(
+                "the mean of no transformations is undefined".to_string(),
+            ));
+        };
+        let weight = Complex64::new(1.0 / transforms.len() as f64, 0.0);
+        let mut base = *first;
+        for _ in 0..MAX_MEAN_ITERATIONS {
+            let base_inverse = base.inverse();
+            let mut average = [[Complex64::new(0.0, 0.0); 2]; 2];
+            for transform in transforms {
+                let relative = transform.compose(&base_inverse);
+                let generator = sl2::log(&sl2::with_positive_trace(&relative.normalized_matrix()));
+                for (row, generator_row) in average.iter_mut().zip(generator) {
+                    for (entry, x) in row.iter_mut().zip(generator_row) {
+                        *entry += x * weight;
+                    }
+                }
+            }
+            let step = exp_transform(&average, 1.0).map_err(|_| {
+                TransformError::InvalidArgument("the mean did not converge".to_string())
+            })?;
+            base = step.compose(&base);
+            if sl2::frobenius_norm(&average) < MEAN_TOLERANCE {
+                return Ok(base);
+            }
+        }
+        Err(TransformError::InvalidArgument(
+            "the mean did not converge".to_string(),
+        ))
+    }
+
+    /// Returns the time-t map φ_t of the flow generated by the transformation.
+    ///
+    /// With X the principal logarithm of the normalized matrix (signed so that
+    /// Re(tr) >= 0), φ_t = exp(t · X). The vector field z ↦ d/dt φ_t(z) at t = 0 is the
+    /// infinitesimal action of the transformation, and φ_t is the map obtained by
+    /// following it for time t. So φ_0 is the identity, φ_1 = self, and the maps form
+    /// a one-parameter group, φ_s ∘ φ_t = φ_{s+t}. The path is the same as
+    /// [`MobiusTransform::fraction`], computed directly from the logarithm.
+    ///
+    /// The coefficients are scaled to order one, so they do not overflow for large
+    /// |t|. As t → ±∞ a hyperbolic or loxodromic flow tends to the constant map onto
+    /// one fixed point, and φ_t saturates: its relative determinant |λ|^{-|t|} falls
+    /// below the rounding error of the coefficients (see [`MobiusTransform::is_valid`]),
+    /// and still φ_t sends every point away from the other fixed point close to
+    /// the limit.
+    ///
+    /// # Errors
+    /// Returns `TransformError::InfiniteCoefficient` if t is infinite or NaN.
+    /// Returns `TransformError::SingularTransform` if the determinant of φ_t rounds to
+    /// exactly zero, e.g. for z ↦ 3^t z beyond |t| ≈ 670, where 3^{-|t|} underflows.
+    pub fn flow(&self, t: f64) -> Result<MobiusTransform, TransformError> {
+        let generator = sl2::log(&sl2::with_positive_trace(&self.normalized_matrix()));
+        exp_transform(&generator, t)
+    }
+
+    /// Samples the orbit of `z0` under the flow, φ_t(z0) for t from `t_start` to `t_end`.
+    ///
+    /// Returns `steps + 1` points at evenly spaced times, including both ends (a single
+    /// point at `t_start` when `steps` is zero). At integer t the points are the
+    /// discrete iterates f^t(z0), and in between they follow the continuous flow (see
+    /// [`MobiusTransform::flow`]), so a loxodromic map traces its double spiral as a
+    /// smooth curve. The logarithm is computed once for the whole path.
+    ///
+    /// For large |t| the points of a hyperbolic or loxodromic path tend to the fixed
+    /// point attracting in that direction of time, even where φ_t itself saturates and
+    /// [`MobiusTransform::flow`] returns an error. Points at infinite or NaN times are NaN.
+    pub fn spiral_path(&self, z0: Complex64, t_start: f64, t_end: f64, steps: usize) -> Vec<Complex64> {
+        let generator = sl2::log(&sl2::with_positive_trace(&self.normalized_matrix()));
+        (0..=steps)
+            .map(|k| {
+                let t = if steps == 0 {
+                    t_start
+                } else {
+                    t_start + (t_end - t_start) * k as f64 / steps as f64
+                };
+                let m = sl2::exp_projective(&sl2::scale(&generator, Complex64::new(t, 0.0)));
+                match MobiusTransform::new_with_tolerance(m[0][0], m[0][1], m[1][0], m[1][1], 0.0) {
+                    Ok(step) => step.apply(z0),
+                    // The determinant underflowed: m is the rank one limit of the flow
+                    Err(_) => apply_matrix(&m, z0),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the pole -d/c, the point sent to infinity.
+    ///
+    /// Returns [`COMPLEX_INFINITY`] when the normalized c is below
+    /// [`DEFAULT_TOLERANCE`]: affine maps send infinity to itself.
+    pub fn pole(&self) -> Complex64 {
+        let n = self.normalize();
+        if n.c.norm() < DEFAULT_TOLERANCE {
+            return COMPLEX_INFINITY;
+        }
+        -n.d / n.c
+    }
+
+    /// Returns a/c, the image of the point at infinity.
+    ///
+    /// This is the pole of the inverse. Returns [`COMPLEX_INFINITY`] when the
+    /// normalized c is below [`DEFAULT_TOLERANCE`].
+    pub fn image_of_infinity(&self) -> Complex64 {
+        let n = self.normalize();
+        if n.c.norm() < DEFAULT_TOLERANCE {
+            return COMPLEX_INFINITY;
+        }
+        n.a / n.c
+    }
+
+    /// Returns the images (f(0), f(1), f(∞)) of the standard triple.
+    ///
+    /// A Möbius transformation is determined by the images of any three distinct
+    /// points, so this triple identifies the map uniquely and is a readable summary in
+    /// tests and debugging output.
+    pub fn standard_triple_image(&self) -> (Complex64, Complex64, Complex64) {
+        (
+            self.apply(Complex64::new(0.0, 0.0)),
+            self.apply(Complex64::new(1.0, 0.0)),
+            self.apply(COMPLEX_INFINITY),
+        )
+    }
+
+    /// Returns the isometric circle as (center, radius).
+    ///
+    /// With the coefficients normalized so that ad - bc = 1, the isometric circle is
+    /// |cz + d| = 1: the circle with center -d/c and radius 1/|c|. Inside it
+    /// |f'(z)| = 1/|cz + d|² > 1, outside it the map contracts, and on it the map
+    /// acts as a Euclidean isometry. It is the basic building block of Ford domains.
+    ///
+    /// Returns `None` when the normalized c is below [`DEFAULT_TOLERANCE`]: affine maps
+    /// scale the whole plane uniformly and have no isometric circle.
+    pub fn isometric_circle(&self) -> Option<(Complex64, f64)> {
+        let n = self.normalize();
+        if n.c.norm() < DEFAULT_TOLERANCE {
+            return None;
+        }
+        Some((-n.d / n.c, 1.0 / n.c.norm()))
+    }
+
+    /// Returns the isometric circle of the inverse transformation as (center, radius).
+    ///
+    /// It has center a/c (the image of infinity) and the same radius as
+    /// [`MobiusTransform::isometric_circle`]; the transformation maps its own
+    /// isometric circle onto this one.
+    pub fn isometric_circle_of_inverse(&self) -> Option<(Complex64, f64)> {
+        self.inverse().isometric_circle()
+    }
+
+    /// Returns the condition number σ_max / σ_min of the coefficient matrix.
+    ///
+    /// It is 1 for rotations of the sphere (unitary matrices, up to scale) and grows as
+    /// the rows become nearly proportional. Roughly log10 of it digits are lost when
+    /// inverting, normalizing or applying the map. It does not depend on the scaling of
+    /// the coefficients, unlike the determinant checked by [`MobiusTransform::new`].
+    ///
+    /// The squared singular values are the eigenvalues of the Gram matrix
+    /// M*M = [[p, r], [r̄, q]] with p = |a|² + |c|², q = |b|² + |d|² and r = āb + c̄d,
+    /// so σ_max² = (p + q + √((p - q)² + 4|r|²)) / 2, a form free of cancellation, and
+    /// σ_min = |det| / σ_max. The matrix is first scaled so
+    /// its largest entry has magnitude 1, avoiding overflow.
+    pub fn condition_number(&self) -> f64 {
+        let scale = self.a.norm().max(self.b.norm()).max(self.c.norm()).max(self.d.norm());
+        let (a, b, c, d) = (self.a / scale, self.b / scale, self.c / scale, self.d / scale);
+        let p = a.norm_sqr() + c.norm_sqr();
+        let q = b.norm_sqr() + d.norm_sqr();
+        let r = (a.conj() * b + c.conj() * d).norm();
+        let det = (a * d - b * c).norm();
+        let sigma_max_sqr = (p + q + ((p - q) * (p - q) + 4.0 * r * r).sqrt()) / 2.0;
+        sigma_max_sqr / det
+    }
+
+    /// Returns the determinant ad - bc.
+    ///
+    /// Computed in doubled precision, so it stays accurate to about machine epsilon
+    /// even when ad and bc nearly cancel, as they do for nearly singular maps.
+    pub fn determinant(&self) -> Complex64 {
+        sl2::determinant(&[[self.a, self.b], [self.c, self.d]])
+    }
+
+    /// Tests whether the transformation is numerically usable.
+    ///
+    /// Returns true if every coefficient is finite and the determinant, relative to
+    /// the largest coefficient squared, is above [`DEFAULT_TOLERANCE`]. The relative
+    /// check does not depend on the scaling of the coefficients. Every transformation
+    /// built by [`MobiusTransform::new`] with coefficients of order one passes it, but
+    /// [`MobiusTransform::new_checked`] and long chains of compositions can produce
+    /// maps that are too close to singular to apply reliably.
+    pub fn is_valid(&self) -> bool {
+        let coefficients = [self.a, self.b, self.c, self.d];
+        if !coefficients.iter().all(|z| z.is_finite()) {
+            return false;
+        }
+        let scale = coefficients.iter().map(|z| z.norm()).fold(0.0, f64::max);
+        if scale == 0.0 {
+            return false;
+        }
+        let [a, b, c, d] = coefficients.map(|z| z / scale);
+        sl2::determinant(&[[a, b], [c, d]]).norm() > DEFAULT_TOLERANCE
+    }
+
+    /// Normalizes the transformation so that ad - bc = 1.
+    ///
+    /// Since the determinant is guaranteed to be non-zero, the normalization
+    /// produces a valid transformation. The determinant is computed accurately (see
+    /// [`MobiusTransform::determinant`]), so each normalized coefficient has a relative
+    /// error of a few machine epsilons even for nearly singular maps.
+    pub fn normalize(&self) -> MobiusTransform {
+        let det_sqrt = self.determinant().sqrt();
+        MobiusTransform::new(
+            self.a / det_sqrt,
+            self.b / det_sqrt,
+            self.c / det_sqrt,
+            self.d / det_sqrt,
+        ).expect("Normalization of a valid transform should always be valid")
+    }
+
+    /// Returns the canonical representative of the transformation.
+    ///
+    /// [`MobiusTransform::normalize`] leaves a sign ambiguity: M and -M both have
+    /// determinant one and represent the same map. This normalizes and then fixes the
+    /// sign by the first coefficient among a, b, c, d with magnitude above
+    /// [`DEFAULT_TOLERANCE`]: its real part is made positive, or, if the real part is
+    /// below [`DEFAULT_TOLERANCE`] in magnitude, its imaginary part. The tolerance
+    /// keeps rounding noise in a component that should be zero from deciding the sign.
+    ///
+    /// Two transformations are the same map exactly when their canonical coefficients
+    /// agree, up to rounding.
+    pub fn canonical(&self) -> MobiusTransform {
+        let n = self.normalize();
+        let leading = [n.a, n.b, n.c, n.d]
+            .into_iter()
+            .find(|z| z.norm() > DEFAULT_TOLERANCE)
+            .expect("A normalized transform has a non-zero coefficient");
+        let negative = if leading.re.abs() > DEFAULT_TOLERANCE {
+            leading.re < 0.0
+        } else {
+            leading.im < 0.0
+        };
+        if negative {
+            // Negation keeps the determinant, so only a zero check applies (see `inverse`)
+            MobiusTransform::new_with_tolerance(-n.a, -n.b, -n.c, -n.d, 0.0)
+                .expect("Negation of a valid transform should always be valid")
+        } else {
+            n
+        }
+    }
+
+    /// Returns the transformation with complex-conjugated coefficients.
+    ///
+    /// This is z ↦ conj(f(conj(z))).
+    pub(crate) fn conj(&self) -> MobiusTransform {
+        // The determinant is the conjugate of that of self, which may be small for maps
+        // built by `compose` or `new_checked`, so only a zero check applies
+        MobiusTransform::new_with_tolerance(self.a.conj(), self.b.conj(), self.c.conj(), self.d.conj(), 0.0)
+            .expect("Conjugate of a valid transform should always be valid")
+    }
+
+    /// Returns the coefficients (a, b, c, d).
+    pub(crate) fn coefficients(&self) -> (Complex64, Complex64, Complex64, Complex64) {
+        (self.a, self.b, self.c, self.d)
+    }
+
+    /// Returns the matrix normalized to determinant one.
+    pub(crate) fn normalized_matrix(&self) -> Matrix2 {
+        let n = self.normalize();
+        [[n.a, n.b], [n.c, n.d]]
+    }
+}
+
+impl MobiusTransform {
+    /// Returns the canonical coefficients rounded to multiples of [`EQUALITY_QUANTUM`],
+    /// as the bit patterns of the rounded values.
+    fn quantized_key(&self) -> [u64; 8] {
+        let n = self.canonical();
+        let quantize = |x: f64| {
+            // Adding 0.0 turns -0.0 into 0.0
+            ((x / EQUALITY_QUANTUM).round() + 0.0).to_bits()
+        };
+        [
+            quantize(n.a.re), quantize(n.a.im),
+            quantize(n.b.re), quantize(n.b.im),
+            quantize(n.c.re), quantize(n.c.im),
+            quantize(n.d.re), quantize(n.d.im),
+        ]
+    }
+}
+
+/// Formats the map as `(az + b) / (cz + d)`.
+///
+/// Coefficients are written as stored (not normalized) by the shared complex
+/// formatting of the crate, rounded to the formatter's precision (4 decimals by
+/// default), for example `((2+i)z + 1) / ((1+i)z + 3)`. Use
+/// [`MobiusTransform::to_coeff_string`] for an exact, parseable form.
+impl fmt::Display for MobiusTransform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+        let coefficient = |z: Complex64| {
+            let formatted = format_complex(z, precision);
+            // Parenthesize sums, such as 2+i, but not lone terms, such as -i
+            if formatted.chars().skip(1).any(|ch| ch == '+' || ch == '-') {
+                format!("({})", formatted)
+            } else {
+                formatted
+            }
+        };
+        let slope = |z: Complex64| match coefficient(z).as_str() {
+            "1" => String::new(),
+            "-1" => "-".to_string(),
+            other => other.to_string(),
+        };
+        write!(
+            f,
+            "({}z + {}) / ({}z + {})",
+            slope(self.a),
+            coefficient(self.b),
+            slope(self.c),
+            coefficient(self.d)
+        )
+    }
+}
+
+/// Equality of the maps, up to scaling of the coefficients.
+///
+/// Two transformations are equal when their canonical coefficients (see
+/// [`MobiusTransform::canonical`]) agree after rounding to a grid of spacing 1e-8.
+/// This makes `==` an equivalence relation consistent with `Hash`, so transforms can
+/// be stored in hash sets and maps. The caveat of any such quantization is that two
+/// maps whose coefficients differ by far less than 1e-8 compare unequal when they
+/// round to different grid points. Use [`MobiusTransform::approx_eq`] for a
+/// tolerance-based comparison instead.
+impl PartialEq for MobiusTransform {
+    fn eq(&self, other: &Self) -> bool {
+        self.quantized_key() == other.quantized_key()
+    }
+}
+
+impl Eq for MobiusTransform {}
+
+/// Hashes the quantized canonical coefficients, consistently with `PartialEq`.
+impl Hash for MobiusTransform {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.quantized_key().hash(state);
+    }
+}
+
+/// Parses a complex number of the form `<re><sign><im>i`, e.g. `2-1.5i` or `-1e-3+0i`.
+fn parse_complex(s: &str) -> Option<Complex64> {
+    let body = s.strip_suffix('i')?;
+    // The sign separating the parts is the last one that does not start the string
+    // or belong to an exponent
+    let bytes = body.as_bytes();
+    let split = (1..bytes.len())
+        .rev()
+        .find(|&i| (bytes[i] == b'+' || bytes[i] == b'-') && !matches!(bytes[i - 1], b'e' | b'E'))?;
+    let re = body[..split].parse::<f64>().ok()?;
+    let im = body[split..].parse::<f64>().ok()?;
+    Some(Complex64::new(re, im))
+}
+
+/// Computes 1/z without overflowing |z|² for large finite z.
+fn stable_reciprocal(z: Complex64) -> Complex64 {
+    let scale = z.re.abs().max(z.im.abs());
+    let scaled = z / scale;
+    scaled.conj() / scaled.norm_sqr() / scale
+}
+
+/// Returns the transformation exp(t · generator) of a traceless generator.
+///
+/// The matrix is computed by [`sl2::exp_projective`], which does not overflow, and
+/// accepted whenever its determinant is non-zero (see [`MobiusTransform::flow`]). A
+/// non-finite t gives `TransformError::InfiniteCoefficient`, and a determinant that
+/// rounds to zero `TransformError::SingularTransform`.
+fn exp_transform(generator: &Matrix2, t: f64) -> Result<MobiusTransform, TransformError> {
+    if !t.is_finite() {
+        return Err(TransformError::InfiniteCoefficient);
+    }
+    let m = sl2::exp_projective(&sl2::scale(generator, Complex64::new(t, 0.0)));
+    MobiusTransform::new_with_tolerance(m[0][0], m[0][1], m[1][0], m[1][1], 0.0)
+}
+
+/// Applies a possibly singular matrix to z as a linear map of homogeneous coordinates,
+/// returning the image as a point rather than the pair of
+/// [`MobiusTransform::apply_homogeneous`].
+///
+/// A rank one matrix sends every point to the point spanning its image, except the
+/// point spanning its kernel, which is returned unchanged.
+fn apply_matrix(m: &Matrix2, z: Complex64) -> Complex64 {
+    let (numerator, denominator) = if is_infinity(z) {
+        (m[0][0], m[1][0])
+    } else {
+        (m[0][0] * z + m[0][1], m[1][0] * z + m[1][1])
+    };
+    let zero = Complex64::new(0.0, 0.0);
+    if numerator == zero && denominator == zero {
+        return z;
+    }
+    if denominator == zero {
+        return COMPLEX_INFINITY;
+    }
+    normalize_infinity(numerator / denominator)
+}
+
+/// Returns the summed chordal length of the paths exp(t · generator)(z) for t in
+/// [0, 1], each sampled at [`PATH_LENGTH_STEPS`] steps.
+fn path_length(generator: &Matrix2, points: &[Complex64]) -> Result<f64, TransformError> {
+    let mut previous = points.to_vec();
+    let mut length = 0.0;
+    for k in 1..=PATH_LENGTH_STEPS {
+        let t = k as f64 / PATH_LENGTH_STEPS as f64;
+        let step = exp_transform(generator, t)?;
+        for (z, last) in points.iter().zip(previous.iter_mut()) {
+            let current = step.apply(*z);
+            length += chordal_distance(*last, current);
+            *last = current;
+        }
+    }
+    Ok(length)
+}
+
+/// Minimum determinant magnitude accepted by [`MobiusTransform::random`].
+///
+/// Kept well above the singularity threshold used by `new` so random
+/// transforms are never close to degenerate.
+#[cfg(feature = "rand")]
+const RANDOM_MIN_DETERMINANT: f64 = 1e-3;
+
+#[cfg(feature = "rand")]
+impl MobiusTransform {
+    /// Creates a random transformation.
+    ///
+    /// Each coefficient is drawn independently from a standard complex Gaussian
+    /// (real and imaginary parts are N(0, 1/2), so E|z|² = 1). Draws whose
+    /// determinant is smaller than 1e-3 in magnitude are rejected and retried,
+    /// so the result is always safely above the singular threshold of `new`.
+    ///
+    /// Requires the `rand` feature.
+    pub fn random(rng: &mut impl rand::Rng) -> MobiusTransform {
+        loop {
+            let a = standard_complex_gaussian(rng);
+            let b = standard_complex_gaussian(rng);
+            let c = standard_complex_gaussian(rng);
+            let d = standard_complex_gaussian(rng);
+
+            if (a * d - b * c).norm() > RANDOM_MIN_DETERMINANT {
+                if let Ok(transform) = MobiusTransform::new(a, b, c, d) {
+                    return transform;
+                }
+            }
+        }
+    }
+
+    /// Creates a random transformation normalized so that ad - bc = 1.
+    ///
+    /// Requires the `rand` feature.
+    pub fn random_normalized(rng: &mut impl rand::Rng) -> MobiusTransform {
+        MobiusTransform::random(rng).normalize()
+    }
+}
+
+/// Draws a sample from the standard complex Gaussian using the Box-Muller transform.
+#[cfg(feature = "rand")]
+fn standard_complex_gaussian(rng: &mut impl rand::Rng) -> Complex64 {
+    // u1 lies in (0, 1] so the logarithm is finite
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+    let r = (-u1.ln()).sqrt();
+    let theta = 2.0 * std::f64::consts::PI * u2;
+    Complex64::new(r * theta.cos(), r * theta.sin())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Asserts that `m` acts as the identity on a handful of sample points.
+    fn assert_acts_as_identity(m: &MobiusTransform) {
+        let samples = [
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(-0.5, 2.0),
+            Complex64::new(3.0, -1.5),
+        ];
+        for z in samples {
+            assert!((m.apply(z) - z).norm() < 1e-10, "{:?} moved to {:?}", z, m.apply(z));
+        }
+    }
+
+    #[test]
+    fn test_identity() {
+        let id = MobiusTransform::identity();
+        let z = Complex64::new(3.0, 4.0);
+        let result = id.apply(z);
+        assert!((result - z).norm() < 1e-10);
+    }
+    
+    #[test]
+    fn test_identity_at_infinity() {
+        let id = MobiusTransform::identity();
+        let result = id.apply(COMPLEX_INFINITY);
+        assert!(is_infinity(result));
+    }
+
+    #[test]
+    fn test_composition() {
+        let m1 = MobiusTransform::new(
+            Complex64::new(2.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        let m2 = MobiusTransform::new(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
         ).unwrap();
         
         let composed = m1.compose(&m2);
@@ -220,97 +1713,1722 @@ mod tests {
     }
 
     #[test]
-    fn test_inverse() {
+    fn test_compose_all() {
+        let f = MobiusTransform::from_affine(Complex64::new(2.0, 0.0), Complex64::new(1.0, 0.0)).unwrap();
+        let g = MobiusTransform::new(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap();
+        let h = MobiusTransform::translation(Complex64::new(0.0, 1.0)).unwrap();
+
+        let composed = MobiusTransform::compose_all(&[f, g, h]);
+        assert!(composed.approx_eq(&f.compose(&g.compose(&h))));
+        assert!(composed.approx_eq(&f.compose(&g).compose(&h)));
+
+        // The last transform is applied first
+        let z = Complex64::new(1.0, 1.0);
+        assert!((composed.apply(z) - f.apply(g.apply(h.apply(z)))).norm() < 1e-10);
+
+        assert!(MobiusTransform::compose_all(&[]).approx_eq(&MobiusTransform::identity()));
+        assert!(MobiusTransform::compose_all(&[g]).approx_eq(&g));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let inv = m.inverse();
+        let z = Complex64::new(2.0, 3.0);
+        
+        let result = inv.apply(m.apply(z));
+        assert!((result - z).norm() < 1e-10);
+    }
+    
+    #[test]
+    fn test_inverse_of_nearly_singular_transform() {
+        // Determinant 2⁻³⁰ ≈ 9.3e-10, just above the singularity cutoff
+        let m = MobiusTransform::new(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0 + 2.0_f64.powi(-30), 0.0),
+        ).unwrap();
+        assert_eq!(m.determinant(), Complex64::new(2.0_f64.powi(-30), 0.0));
+
+        let inv = m.inverse();
+        assert!(inv.compose(&m).approx_eq(&MobiusTransform::identity()));
+        assert!(m.compose(&inv).approx_eq(&MobiusTransform::identity()));
+
+        // The normalized matrix has determinant one to rounding
+        let n = m.normalize();
+        assert!((n.determinant() - Complex64::new(1.0, 0.0)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_display() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        assert_eq!(m.to_string(), "((2+i)z + 1) / ((1+i)z + 3)");
+
+        let m = MobiusTransform::new(
+            Complex64::new(0.0, -1.0),
+            Complex64::new(1.0 / 3.0, 0.0),
+            Complex64::new(-1.0, 0.0),
+            Complex64::new(0.0, 2.0),
+        ).unwrap();
+        assert_eq!(format!("{:.2}", m), "(-iz + 0.33) / (-z + 2i)");
+        assert_eq!(MobiusTransform::identity().to_string(), "(z + 0) / (0z + 1)");
+    }
+
+    #[test]
+    fn test_inverse_of_tiny_determinant() {
+        // Accepted by new_checked although the determinant is below DEFAULT_TOLERANCE
+        let (m, _) = MobiusTransform::new_checked(
+            Complex64::new(1e-6, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1e-6, 0.0),
+        ).unwrap();
+        assert!(m.inverse().approx_eq(&MobiusTransform::identity()));
+    }
+
+    #[test]
+    fn test_inverse_of_large_determinant() {
+        let m = MobiusTransform::from_affine(Complex64::new(1e11, 0.0), Complex64::new(3.0, 0.0)).unwrap();
+        let inv = m.inverse();
+        assert!(inv.compose(&m).approx_eq(&MobiusTransform::identity()));
+        assert!((inv.apply(m.apply(Complex64::new(2.0, 1.0))) - Complex64::new(2.0, 1.0)).norm() < 1e-10);
+    }
+
+    #[test]
+    fn test_apply_infinity_when_c_nonzero_a_nonzero() {
+        // f(z) = (2z + 1) / (z + 1)
+        // c ≠ 0 && a ≠ 0, so f(∞) = a/c = 2/1 = 2
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        let result = m.apply(COMPLEX_INFINITY);
+        assert!((result - Complex64::new(2.0, 0.0)).norm() < 1e-10);
+    }
+    
+    #[test]
+    fn test_apply_infinity_when_c_nonzero_a_zero() {
+        // f(z) = 1 / (z + 1) (with a=0, c≠0)
+        // c ≠ 0 && a == 0, so f(∞) = 0
+        let m = MobiusTransform::new(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        let result = m.apply(COMPLEX_INFINITY);
+        assert_eq!(result, Complex64::new(0.0, 0.0));
+    }
+    
+    #[test]
+    fn test_apply_infinity_when_c_zero_a_nonzero() {
+        // f(z) = 2z + 1 (with c=0, a≠0)
+        // c == 0 && a ≠ 0, so f(∞) = ∞
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        let result = m.apply(COMPLEX_INFINITY);
+        assert!(is_infinity(result));
+    }
+    
+    #[test]
+    fn test_a_and_c_zero_is_rejected() {
+        // a = c = 0 would be the constant map z ↦ b/d
+        let (zero, two, three) = (Complex64::new(0.0, 0.0), Complex64::new(2.0, 0.0), Complex64::new(3.0, 0.0));
+        assert_eq!(MobiusTransform::new(zero, two, zero, three).unwrap_err(), TransformError::SingularTransform);
+        for tol in [0.0, -1.0] {
+            assert_eq!(
+                MobiusTransform::new_with_tolerance(zero, two, zero, three, tol).unwrap_err(),
+                TransformError::SingularTransform
+            );
+        }
+        let matrix = Array2::from_shape_vec((2, 2), vec![zero, two, zero, three]).unwrap();
+        assert_eq!(MobiusTransform::from_matrix(&matrix).unwrap_err(), TransformError::SingularTransform);
+    }
+
+    #[test]
+    fn test_apply_infinity_with_tiny_a_and_c() {
+        // Valid maps whose a and c are both below the tolerance
+        let m = MobiusTransform::new_with_tolerance(
+            Complex64::new(2e-12, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1e-12, 0.0),
+            Complex64::new(1.0, 0.0),
+            0.0,
+        ).unwrap();
+        assert!((m.apply(COMPLEX_INFINITY) - Complex64::new(2.0, 0.0)).norm() < 1e-12);
+
+        let affine = MobiusTransform::new_with_tolerance(
+            Complex64::new(1e-12, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            0.0,
+        ).unwrap();
+        assert!(is_infinity(affine.apply(COMPLEX_INFINITY)));
+    }
+
+    #[test]
+    fn test_apply_maps_to_infinity() {
+        // f(z) = 1/z
+        // f(0) = ∞
+        let m = MobiusTransform::new(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap();
+        let result = m.apply(Complex64::new(0.0, 0.0));
+        assert!(is_infinity(result));
+    }
+
+    #[test]
+    fn test_precompose() {
+        // Indicator of the unit disk, pulled back through z ↦ z/2 + 1
+        let f = MobiusTransform::from_affine(Complex64::new(0.5, 0.0), Complex64::new(1.0, 0.0)).unwrap();
+        let in_disk = |w: Complex64| w.norm() < 1.0;
+        let pulled_back = f.precompose(in_disk);
+
+        // f(z) lies in the unit disk exactly when |z + 2| < 2
+        assert!(pulled_back(Complex64::new(-2.0, 0.0)));
+        assert!(pulled_back(Complex64::new(-1.0, 1.0)));
+        assert!(!pulled_back(Complex64::new(0.0, 0.0)));
+        assert!(!pulled_back(Complex64::new(-2.0, 2.5)));
+        assert!(!pulled_back(COMPLEX_INFINITY));
+
+        for z in [Complex64::new(0.3, -0.7), Complex64::new(-3.0, 0.5)] {
+            assert_eq!(pulled_back(z), in_disk(f.apply(z)));
+        }
+    }
+
+    #[test]
+    fn test_apply_iter_matches_apply_batch() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let points = Array1::from_vec(vec![
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, -2.0),
+            Complex64::new(-1.5, -1.5),
+            COMPLEX_INFINITY,
+        ]);
+
+        let streamed: Vec<Complex64> = m.apply_iter(points.iter().copied()).collect();
+        assert_eq!(streamed, m.apply_batch(&points).to_vec());
+        assert_eq!(m.apply_iter(std::iter::empty()).count(), 0);
+    }
+
+    #[test]
+    fn test_preimage_batch_inverts_apply_batch() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let points = Array1::from_vec(vec![
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, -2.0),
+            Complex64::new(-1.5, 1.5), // The pole, sent to infinity
+            COMPLEX_INFINITY,
+        ]);
+
+        let images = m.apply_batch(&points);
+        assert!(is_infinity(images[2]));
+        let round_trip = m.preimage_batch(&images);
+        for (z, w) in points.iter().zip(round_trip.iter()) {
+            if is_infinity(*z) {
+                assert!(is_infinity(*w));
+            } else {
+                assert!((z - w).norm() < 1e-10, "{} != {}", z, w);
+            }
+        }
+    }
+
+    #[test]
+    fn test_apply_batch_masked() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let points = Array1::from_vec(vec![
+            Complex64::new(0.0, 0.0),
+            Complex64::new(-1.5, 1.5), // The pole
+            Complex64::new(1.0, -2.0),
+            COMPLEX_INFINITY,
+        ]);
+
+        let (images, mask) = m.apply_batch_masked(&points);
+        assert_eq!(images, m.apply_batch(&points));
+        assert_eq!(mask, vec![false, true, false, false]);
+
+        // Affine maps send infinity to itself
+        let affine = MobiusTransform::translation(Complex64::new(1.0, 0.0)).unwrap();
+        assert_eq!(affine.apply_batch_masked(&points).1, vec![false, false, false, true]);
+    }
+
+    #[test]
+    fn test_apply_grid() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let points = Array1::from_iter((0..6).map(|k| Complex64::new(k as f64, 1.0)));
+        let grid = m.apply_grid(&points, 2, 3).unwrap();
+        assert_eq!(grid.shape(), &[2, 3]);
+        assert_eq!(grid[[1, 0]], m.apply(points[3]));
+
+        // Empty input is fine, for batches and grids alike
+        let empty = Array1::<Complex64>::from_vec(Vec::new());
+        assert_eq!(m.apply_batch(&empty).len(), 0);
+        assert_eq!(m.apply_grid(&empty, 0, 4).unwrap().shape(), &[0, 4]);
+
+        // A wrongly sized input is a clean error, not a panic
+        assert_eq!(
+            m.apply_grid(&points, 4, 2).unwrap_err(),
+            TransformError::ShapeMismatch { expected: vec![4, 2], found: vec![6] }
+        );
+        assert!(m.apply_grid(&points, usize::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn test_compile_matches_apply() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let compiled = m.compile();
+
+        let pole = -m.d / m.c;
+        let points = [
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, -2.0),
+            Complex64::new(-1e3, 1e-3),
+            Complex64::new(1e200, 1e200),
+            pole,
+            COMPLEX_INFINITY,
+        ];
+        for z in points {
+            assert_eq!(compiled(z), m.apply(z), "z = {}", z);
+        }
+        assert!(compiled(Complex64::new(f64::NAN, 0.0)).is_nan());
+    }
+
+    #[test]
+    fn test_apply_point() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let z = Complex64::new(1.0, -2.0);
+        let w = m.apply(z);
+        assert_eq!(m.apply_point((z.re, z.im)), (w.re, w.im));
+        assert_eq!(m.apply_point(z), w);
+
+        // Round trip through the inverse
+        let (x, y) = m.inverse().apply_point(m.apply_point((1.0, -2.0)));
+        assert!((x - 1.0).abs() < 1e-10 && (y + 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_try_apply() {
+        // f(z) = 1/z
+        let m = MobiusTransform::new(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap();
+        assert_eq!(m.try_apply(Complex64::new(2.0, 0.0)), Ok(Complex64::new(0.5, 0.0)));
+        assert_eq!(m.try_apply(Complex64::new(0.0, 0.0)), Err(AtInfinity));
+        assert_eq!(m.try_apply(COMPLEX_INFINITY), Ok(Complex64::new(0.0, 0.0)));
+
+        // Affine maps fix infinity
+        let affine = MobiusTransform::translation(Complex64::new(1.0, 0.0)).unwrap();
+        assert_eq!(affine.try_apply(COMPLEX_INFINITY), Err(AtInfinity));
+    }
+
+    #[test]
+    fn test_zero_determinant() {
+        let result = MobiusTransform::new(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(2.0, 0.0),
+            Complex64::new(2.0, 0.0),
+            Complex64::new(4.0, 0.0),
+        );
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), TransformError::SingularTransform);
+    }
+    
+    #[test]
+    fn test_infinite_coefficient() {
+        let result = MobiusTransform::new(
+            COMPLEX_INFINITY,
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        );
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), TransformError::InfiniteCoefficient);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_transforms_are_valid() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(530);
+        for _ in 0..1000 {
+            let m = MobiusTransform::random(&mut rng);
+            let revalidated = MobiusTransform::new(m.a, m.b, m.c, m.d);
+            assert!(revalidated.is_ok());
+            assert!(m.determinant().norm() > RANDOM_MIN_DETERMINANT);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_normalized_has_unit_determinant() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(531);
+        for _ in 0..100 {
+            let m = MobiusTransform::random_normalized(&mut rng);
+            assert!((m.determinant() - Complex64::new(1.0, 0.0)).norm() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_apply_with_tolerance_near_pole() {
+        // f(z) = 1/z with a point at distance 1e-8 from the pole
+        let m = MobiusTransform::new(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap();
+        let z = Complex64::new(1e-8, 0.0);
+
+        assert!(is_infinity(m.apply_with_tolerance(z, 1e-6)));
+
+        let result = m.apply_with_tolerance(z, 1e-12);
+        assert!(!is_infinity(result));
+        assert!((result - Complex64::new(1e8, 0.0)).norm() < 1e-2);
+
+        // The default tolerance (1e-10) resolves this point as finite
+        assert_eq!(m.apply(z), result);
+    }
+
+    #[test]
+    fn test_new_with_tolerance() {
+        // Determinant is 1e-8
+        let a = Complex64::new(1e-4, 0.0);
+        let b = Complex64::new(0.0, 0.0);
+        let c = Complex64::new(0.0, 0.0);
+        let d = Complex64::new(1e-4, 0.0);
+
+        let result = MobiusTransform::new_with_tolerance(a, b, c, d, 1e-6);
+        assert_eq!(result.unwrap_err(), TransformError::SingularTransform);
+        assert!(MobiusTransform::new_with_tolerance(a, b, c, d, 1e-12).is_ok());
+    }
+
+    #[test]
+    fn test_apply_large_input_does_not_produce_nan() {
+        // f(z) = (2z + 1) / (z + 1), so f(z) → 2 as z → ∞.
+        // |z|² overflows inside the complex division for z = 1e200.
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        let result = m.apply(Complex64::new(1e200, -1e200));
+        assert!(!result.is_nan());
+        assert!((result - Complex64::new(2.0, 0.0)).norm() < 1e-10);
+
+        // An affine map sends a huge input beyond f64 range, which is infinity
+        let affine = MobiusTransform::new(
+            Complex64::new(1e200, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1e-200, 0.0),
+        ).unwrap();
+        assert_eq!(affine.apply(Complex64::new(1e200, 0.0)), COMPLEX_INFINITY);
+    }
+
+    #[test]
+    fn test_apply_at_pole_with_zero_tolerance() {
+        // f(z) = 1/z evaluated exactly at the pole divides by zero
+        let m = MobiusTransform::new(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap();
+        let result = m.apply_with_tolerance(Complex64::new(0.0, 0.0), 0.0);
+        assert_eq!(result, COMPLEX_INFINITY);
+    }
+
+    #[test]
+    fn test_apply_underflow_does_not_produce_nan() {
+        // f(z) = 1e-300 / z, so f(1e-300) = 1, but the products inside the
+        // complex division underflow to zero
+        let m = MobiusTransform::new_with_tolerance(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1e-300, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            0.0,
+        ).unwrap();
+        let result = m.apply_with_tolerance(Complex64::new(1e-300, 0.0), 0.0);
+        assert!((result - Complex64::new(1.0, 0.0)).norm() < 1e-10);
+    }
+
+    #[test]
+    fn test_apply_nan_input() {
+        let m = MobiusTransform::identity();
+        assert!(m.apply(Complex64::new(f64::NAN, 0.0)).is_nan());
+    }
+
+    #[test]
+    fn test_nan_coefficient() {
+        let result = MobiusTransform::new(
+            Complex64::new(f64::NAN, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        );
+        assert_eq!(result.unwrap_err(), TransformError::InfiniteCoefficient);
+    }
+
+    #[test]
+    fn test_affine_round_trip() {
+        let scale_rot = Complex64::new(1.5, -2.0);
+        let translation = Complex64::new(-0.5, 3.0);
+        let m = MobiusTransform::from_affine(scale_rot, translation).unwrap();
+
+        let z = Complex64::new(0.7, 0.2);
+        assert!((m.apply(z) - (scale_rot * z + translation)).norm() < 1e-10);
+
+        let (s, t) = m.to_affine().unwrap();
+        assert_eq!(s, scale_rot);
+        assert_eq!(t, translation);
+    }
+
+    #[test]
+    fn test_to_affine_divides_by_d() {
+        // f(z) = (4z + 2) / 2 = 2z + 1
+        let m = MobiusTransform::new(
+            Complex64::new(4.0, 0.0),
+            Complex64::new(2.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(2.0, 0.0),
+        ).unwrap();
+        let (s, t) = m.to_affine().unwrap();
+        assert_eq!(s, Complex64::new(2.0, 0.0));
+        assert_eq!(t, Complex64::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_to_affine_non_affine() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        assert!(m.to_affine().is_none());
+    }
+
+    #[test]
+    fn test_from_affine_zero_scale() {
+        let result = MobiusTransform::from_affine(Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0));
+        assert_eq!(result.unwrap_err(), TransformError::SingularTransform);
+    }
+
+    #[test]
+    fn test_from_matrix_round_trip() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let matrix = m.to_matrix();
+        let round_trip = MobiusTransform::from_matrix(&matrix).unwrap();
+        assert_eq!(round_trip.to_matrix(), matrix);
+    }
+
+    #[test]
+    fn test_from_matrix_wrong_shape() {
+        let matrix = Array2::from_elem((3, 2), Complex64::new(1.0, 0.0));
+        let result = MobiusTransform::from_matrix(&matrix);
+        assert_eq!(
+            result.unwrap_err(),
+            TransformError::ShapeMismatch { expected: vec![2, 2], found: vec![3, 2] }
+        );
+    }
+
+    #[test]
+    fn test_from_matrix_singular() {
+        let matrix = Array2::from_shape_vec((2, 2), vec![
+            Complex64::new(1.0, 0.0),
+            Complex64::new(2.0, 0.0),
+            Complex64::new(2.0, 0.0),
+            Complex64::new(4.0, 0.0),
+        ]).unwrap();
+        let result = MobiusTransform::from_matrix(&matrix);
+        assert_eq!(result.unwrap_err(), TransformError::SingularTransform);
+    }
+
+    #[test]
+    fn test_array_round_trip() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let values = m.to_array();
+        assert_eq!(values, [2.0, 1.0, 1.0, 0.0, 1.0, 1.0, 3.0, 0.0]);
+        assert_eq!(MobiusTransform::from_array(values).unwrap().to_array(), values);
+    }
+
+    #[test]
+    fn test_from_array_invalid() {
+        let singular = [1.0, 0.0, 2.0, 0.0, 2.0, 0.0, 4.0, 0.0];
+        assert_eq!(MobiusTransform::from_array(singular).unwrap_err(), TransformError::SingularTransform);
+        let infinite = [1.0, 0.0, 0.0, f64::NAN, 0.0, 0.0, 1.0, 0.0];
+        assert_eq!(MobiusTransform::from_array(infinite).unwrap_err(), TransformError::InfiniteCoefficient);
+    }
+
+    #[test]
+    fn test_commutator_shared_fixed_points() {
+        // z ↦ 2z and z ↦ iz both fix 0 and ∞, so they commute
+        let scale = MobiusTransform::new(
+            Complex64::new(2.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        let rotate = MobiusTransform::new(
+            Complex64::new(0.0, 1.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        assert_acts_as_identity(&scale.commutator(&rotate));
+    }
+
+    #[test]
+    fn test_commutator_generic() {
+        let m1 = MobiusTransform::new(
+            Complex64::new(2.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        let m2 = MobiusTransform::new(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        let commutator = m1.commutator(&m2);
+
+        let z = Complex64::new(0.5, 0.5);
+        let expected = m1.apply(m2.apply(m1.inverse().apply(m2.inverse().apply(z))));
+        assert!((commutator.apply(z) - expected).norm() < 1e-10);
+        assert!((commutator.apply(z) - z).norm() > 1e-3);
+    }
+
+    #[test]
+    fn test_approx_eq_up_to_scalar() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let scaled = MobiusTransform::new(m.a * 3.0, m.b * 3.0, m.c * 3.0, m.d * 3.0).unwrap();
+        let negated = MobiusTransform::new(-m.a, -m.b, -m.c, -m.d).unwrap();
+
+        assert!(m.approx_eq(&m));
+        assert!(m.approx_eq(&scaled));
+        assert!(m.approx_eq(&negated));
+        assert!(m.approx_eq(&m.inverse().inverse()));
+        assert!(!m.approx_eq(&MobiusTransform::identity()));
+    }
+
+    #[test]
+    fn test_commutes_with() {
+        // Rotations about the same center commute
+        let center = Complex64::new(1.0, 1.0);
+        let rotation_about = |center: Complex64, angle: f64| {
+            let rotation = Complex64::from_polar(1.0, angle);
+            MobiusTransform::from_affine(rotation, center * (1.0 - rotation)).unwrap()
+        };
+        let r1 = rotation_about(center, 0.3);
+        let r2 = rotation_about(center, 1.1);
+        assert!(r1.commutes_with(&r2, 1e-10));
+
+        // Rotations about different centers do not
+        let r3 = rotation_about(Complex64::new(-2.0, 0.5), 1.1);
+        assert!(!r1.commutes_with(&r3, 1e-10));
+    }
+
+    #[test]
+    fn test_sqrt_squares_to_self() {
+        let loxodromic = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let parabolic = MobiusTransform::from_affine(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(2.0, -1.0),
+        ).unwrap();
+        let half_turn = MobiusTransform::from_affine(
+            Complex64::new(-1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap();
+
+        for m in [loxodromic, parabolic, half_turn, MobiusTransform::identity()] {
+            let root = m.sqrt();
+            assert!(root.compose(&root).approx_eq(&m));
+        }
+    }
+
+    #[test]
+    fn test_sqrt_principal_branch() {
+        // The square root of a rotation by 2π/3 is the rotation by π/3
+        let angle = 2.0 * std::f64::consts::PI / 3.0;
+        let rotation = MobiusTransform::from_affine(Complex64::from_polar(1.0, angle), Complex64::new(0.0, 0.0)).unwrap();
+        let half = MobiusTransform::from_affine(Complex64::from_polar(1.0, angle / 2.0), Complex64::new(0.0, 0.0)).unwrap();
+        assert!(rotation.sqrt().approx_eq(&half));
+
+        // The square root of a translation by t is the translation by t/2
+        let t = Complex64::new(2.0, -1.0);
+        let translation = MobiusTransform::from_affine(Complex64::new(1.0, 0.0), t).unwrap();
+        let half = MobiusTransform::from_affine(Complex64::new(1.0, 0.0), t / 2.0).unwrap();
+        assert!(translation.sqrt().approx_eq(&half));
+    }
+
+    #[test]
+    fn test_distance() {
+        let m1 = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let m2 = MobiusTransform::new(
+            Complex64::new(0.0, 1.0),
+            Complex64::new(2.0, 0.0),
+            Complex64::new(-1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+        ).unwrap();
+
+        assert!(m1.distance(&m1) < 1e-10);
+
+        // Scaling the coefficients does not change the map
+        let scaled = MobiusTransform::new(m1.a * -2.0, m1.b * -2.0, m1.c * -2.0, m1.d * -2.0).unwrap();
+        assert!(m1.distance(&scaled) < 1e-10);
+
+        let d12 = m1.distance(&m2);
+        let d21 = m2.distance(&m1);
+        assert!(d12 > 1e-3);
+        assert!((d12 - d21).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_distance_of_translations() {
+        // The logarithm of a translation by t is [[0, t], [0, 0]]
+        let t = Complex64::new(3.0, 4.0);
+        let translation = MobiusTransform::from_affine(Complex64::new(1.0, 0.0), t).unwrap();
+        let identity = MobiusTransform::identity();
+        assert!((identity.distance(&translation) - 5.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_canonical_fixes_sign() {
+        let m = MobiusTransform::new(
+            Complex64::new(-2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let negated = MobiusTransform::new(-m.a, -m.b, -m.c, -m.d).unwrap();
+
+        let c1 = m.canonical();
+        let c2 = negated.canonical();
+        assert_eq!((c1.a, c1.b, c1.c, c1.d), (c2.a, c2.b, c2.c, c2.d));
+        assert!(c1.a.re > 0.0);
+        assert!((c1.determinant() - Complex64::new(1.0, 0.0)).norm() < 1e-12);
+        assert!(c1.approx_eq(&m));
+    }
+
+    #[test]
+    fn test_canonical_leading_coefficient() {
+        // z ↦ -z has normalized a = ±i; a is purely imaginary, so its imaginary part decides
+        let half_turn = MobiusTransform::rotation(std::f64::consts::PI).unwrap();
+        for factor in [1.0, -1.0, 3.0, -0.25] {
+            let scaled = MobiusTransform::new(
+                half_turn.a * factor,
+                half_turn.b * factor,
+                half_turn.c * factor,
+                half_turn.d * factor,
+            ).unwrap();
+            let c = scaled.canonical();
+            assert!((c.a - Complex64::new(0.0, 1.0)).norm() < 1e-12);
+            assert!((c.d - Complex64::new(0.0, -1.0)).norm() < 1e-12);
+        }
+
+        // With a = 0 the sign is fixed by b
+        let inversion = MobiusTransform::new(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(-1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap();
+        assert!(inversion.canonical().b.re > 0.0);
+    }
+
+    #[test]
+    fn test_eq_and_hash_up_to_scalar() {
+        use std::collections::HashSet;
+
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let scaled = MobiusTransform::new(m.a * 3.0, m.b * 3.0, m.c * 3.0, m.d * 3.0).unwrap();
+        let negated = MobiusTransform::new(-m.a, -m.b, -m.c, -m.d).unwrap();
+        assert_eq!(m, scaled);
+        assert_eq!(m, negated);
+        assert_ne!(m, m.inverse());
+
+        let set: HashSet<MobiusTransform> = [m, scaled, negated].into_iter().collect();
+        assert_eq!(set.len(), 1);
+
+        let set: HashSet<MobiusTransform> = [m, m.inverse(), MobiusTransform::identity()].into_iter().collect();
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn test_condition_number() {
+        // Sphere rotations are perfectly conditioned
+        let rotation = MobiusTransform::elliptic(Complex64::new(1.0, 2.0), 0.7).unwrap();
+        assert!((rotation.condition_number() - 1.0).abs() < 1e-10);
+
+        // diag(4, 1) has singular values 4 and 1
+        let scaling = MobiusTransform::from_affine(Complex64::new(4.0, 0.0), Complex64::new(0.0, 0.0)).unwrap();
+        assert!((scaling.condition_number() - 4.0).abs() < 1e-10);
+
+        // Scaling the coefficients does not change the condition number
+        let scaled = MobiusTransform::new(Complex64::new(1e-3, 0.0), Complex64::new(0.0, 0.0), Complex64::new(0.0, 0.0), Complex64::new(4e-3, 0.0)).unwrap();
+        assert!((scaled.condition_number() - 4.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_new_checked() {
+        let (m, condition) = MobiusTransform::new_checked(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0 + 1e-12, 0.0),
+        ).unwrap();
+        assert!(condition > 1e11);
+        assert_eq!(condition, m.condition_number());
+
+        // Tiny but well-conditioned coefficients are rejected by `new` but not here
+        let tiny = Complex64::new(1e-8, 0.0);
+        assert!(MobiusTransform::new(tiny, Complex64::new(0.0, 0.0), Complex64::new(0.0, 0.0), tiny).is_err());
+        let (_, condition) = MobiusTransform::new_checked(tiny, Complex64::new(0.0, 0.0), Complex64::new(0.0, 0.0), tiny).unwrap();
+        assert!((condition - 1.0).abs() < 1e-10);
+
+        let zero = Complex64::new(0.0, 0.0);
+        assert_eq!(
+            MobiusTransform::new_checked(Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0), Complex64::new(2.0, 0.0), Complex64::new(4.0, 0.0)).unwrap_err(),
+            TransformError::SingularTransform
+        );
+        assert!(MobiusTransform::new_checked(Complex64::new(f64::NAN, 0.0), zero, zero, Complex64::new(1.0, 0.0)).is_err());
+    }
+
+    #[test]
+    fn test_apply_polyline_curves_edges() {
+        // z ↦ 1/z maps the segment [1 - i, 1 + i] onto an arc of |w - 1/2| = 1/2
+        let inversion = MobiusTransform::new(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap();
+        let segment = [Complex64::new(1.0, -1.0), Complex64::new(1.0, 1.0)];
+        let image = inversion.apply_polyline(&segment, 9);
+        assert_eq!(image.len(), 11);
+
+        for w in &image {
+            assert!(((w - 0.5).norm() - 0.5).abs() < 1e-10);
+        }
+
+        // The image of the midpoint 1 is 1, far from the chord midpoint 1/2
+        let chord_midpoint = (image[0] + image[10]) / 2.0;
+        assert!((image[5] - Complex64::new(1.0, 0.0)).norm() < 1e-10);
+        assert!((image[5] - chord_midpoint).norm() > 0.4);
+    }
+
+    #[test]
+    fn test_apply_polyline_edge_cases() {
+        let m = MobiusTransform::translation(Complex64::new(1.0, 0.0)).unwrap();
+        assert!(m.apply_polyline(&[], 4).is_empty());
+        assert_eq!(m.apply_polyline(&[Complex64::new(0.0, 0.0)], 4), vec![Complex64::new(1.0, 0.0)]);
+
+        // Vertices only
+        let triangle = [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0), Complex64::new(0.0, 1.0)];
+        assert_eq!(m.apply_polyline(&triangle, 0).len(), 3);
+        assert_eq!(m.apply_polyline(&triangle, 2).len(), 7);
+
+        // No samples on an edge to infinity
+        let ray = [Complex64::new(0.0, 0.0), COMPLEX_INFINITY, Complex64::new(1.0, 0.0)];
+        let image = m.apply_polyline(&ray, 3);
+        assert_eq!(image.len(), 3);
+        assert!(is_infinity(image[1]));
+    }
+
+    #[test]
+    fn test_apply_rect() {
+        let (min, max) = (Complex64::new(-1.0, 0.5), Complex64::new(2.0, 3.0));
+        let corners = [
+            Complex64::new(-1.0, 0.5),
+            Complex64::new(2.0, 0.5),
+            Complex64::new(2.0, 3.0),
+            Complex64::new(-1.0, 3.0),
+            Complex64::new(-1.0, 0.5),
+        ];
+        let outline = MobiusTransform::identity().apply_rect(min, max, 3);
+        assert_eq!(outline.len(), 17);
+        for (k, corner) in corners.iter().enumerate() {
+            assert!((outline[4 * k] - corner).norm() < 1e-12);
+        }
+        // Edge points lie on the rectangle
+        assert!((outline[2] - Complex64::new(0.5, 0.5)).norm() < 1e-12);
+
+        // Swapped corners describe the same rectangle
+        let swapped = MobiusTransform::identity().apply_rect(Complex64::new(2.0, 0.5), Complex64::new(-1.0, 3.0), 0);
+        assert_eq!(swapped.len(), 5);
+        for (w, corner) in swapped.iter().zip(&corners) {
+            assert!((w - corner).norm() < 1e-12);
+        }
+
+        // Under z ↦ 1/z the outline closes and is curved
+        let inversion = MobiusTransform::new(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap();
+        let image = inversion.apply_rect(min, max, 3);
+        assert_eq!(image.first(), image.last());
+        assert!((image[4] - Complex64::new(2.0, 0.5).inv()).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_derivative() {
+        // f(z) = (z - 1) / (z + 1) has f'(z) = 2 / (z + 1)²
+        let m = MobiusTransform::new(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(-1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        ).unwrap();
+        let z = Complex64::new(0.5, 2.0);
+        let expected = 2.0 / ((z + 1.0) * (z + 1.0));
+        assert!((m.derivative(z) - expected).norm() < 1e-10);
+
+        // Agrees with a central difference
+        let h = 1e-6;
+        let estimate = (m.apply(z + h) - m.apply(z - h)) / (2.0 * h);
+        assert!((m.derivative(z) - estimate).norm() < 1e-6);
+
+        assert!(is_infinity(m.derivative(Complex64::new(-1.0, 0.0))));
+        assert_eq!(m.derivative(COMPLEX_INFINITY), Complex64::new(0.0, 0.0));
+
+        // Affine maps have constant slope, also at infinity
+        let affine = MobiusTransform::from_affine(Complex64::new(2.0, 1.0), Complex64::new(1.0, 0.0)).unwrap();
+        assert!((affine.derivative(COMPLEX_INFINITY) - Complex64::new(2.0, 1.0)).norm() < 1e-10);
+    }
+
+    #[test]
+    fn test_infinitesimal_image() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let z = Complex64::new(0.5, -1.0);
+        let radius = 1e-6;
+        let (center, image_radius, rotation) = m.infinitesimal_image(z, radius);
+        assert!((center - m.apply(z)).norm() < 1e-12);
+
+        // Points of the small circle land at distance ≈ image_radius from the center
+        for k in 0..8 {
+            let offset = Complex64::from_polar(radius, k as f64 * std::f64::consts::FRAC_PI_4);
+            let distance = (m.apply(z + offset) - center).norm();
+            assert!((distance - image_radius).abs() < 1e-4 * image_radius);
+        }
+
+        // The point z + radius is turned by the rotation
+        let direction = m.apply(z + radius) - center;
+        assert!((direction.arg() - rotation).abs() < 1e-4);
+
+        // At the pole the image is not a small circle
+        let pole = -m.d / m.c;
+        let (_, image_radius, rotation) = m.infinitesimal_image(pole, radius);
+        assert!(image_radius.is_infinite());
+        assert_eq!(rotation, 0.0);
+    }
+
+    #[test]
+    fn test_pushforward() {
+        // A rotation turns vectors by its angle and keeps their length
+        let angle = 0.9;
+        let rotation = MobiusTransform::rotation(angle).unwrap();
+        let v = Complex64::new(3.0, -1.0);
+        let pushed = rotation.pushforward(Complex64::new(1.0, 2.0), v);
+        assert!((pushed.norm() - v.norm()).abs() < 1e-12);
+        assert!((pushed - v * Complex64::from_polar(1.0, angle)).norm() < 1e-12);
+
+        // Matches a finite difference for a general map
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let z = Complex64::new(0.5, -1.0);
+        let h = 1e-7;
+        let difference = (m.apply(z + v * h) - m.apply(z)) / h;
+        assert!((m.pushforward(z, v) - difference).norm() < 1e-6);
+
+        // At the pole
+        let pole = Complex64::new(-1.5, 1.5);
+        assert!(is_infinity(m.pushforward(pole, v)));
+        assert_eq!(m.pushforward(pole, Complex64::new(0.0, 0.0)), Complex64::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_coefficient_gradients_match_finite_differences() {
+        let (a, b, c, d) = (
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        );
+        let m = MobiusTransform::new(a, b, c, d).unwrap();
+        let h = 1e-6;
+        for z in [Complex64::new(0.5, -0.5), Complex64::new(-2.0, 1.0), COMPLEX_INFINITY] {
+            let gradients = m.coefficient_gradients(z);
+            for (k, gradient) in gradients.iter().enumerate() {
+                // Perturb one coefficient in the real and in the imaginary direction;
+                // f is holomorphic in each coefficient, so both give the same derivative
+                for step in [Complex64::new(h, 0.0), Complex64::new(0.0, h)] {
+                    let perturbed = |sign: f64| {
+                        let mut coefficients = [a, b, c, d];
+                        coefficients[k] += step * sign;
+                        let [a2, b2, c2, d2] = coefficients;
+                        MobiusTransform::new(a2, b2, c2, d2).unwrap().apply(z)
+                    };
+                    // Central difference, accurate to O(h²)
+                    let difference = (perturbed(1.0) - perturbed(-1.0)) / (step * 2.0);
+                    assert!((difference - gradient).norm() < 1e-8, "z = {}, k = {}", z, k);
+                }
+            }
+        }
+
+        assert!(m.coefficient_gradients(m.pole()).iter().all(|g| g.is_nan()));
+        let affine = MobiusTransform::from_affine(Complex64::new(2.0, 0.0), Complex64::new(1.0, 0.0)).unwrap();
+        assert!(affine.coefficient_gradients(COMPLEX_INFINITY).iter().all(|g| g.is_nan()));
+    }
+
+    #[test]
+    fn test_taylor() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let z0 = Complex64::new(0.5, -1.0);
+        let coefficients = m.taylor(z0, 6);
+        assert_eq!(coefficients.len(), 7);
+        assert!((coefficients[0] - m.apply(z0)).norm() < 1e-12);
+        assert!((coefficients[1] - m.derivative(z0)).norm() < 1e-12);
+
+        // The truncated series matches f near z0
+        let h = Complex64::new(0.01, 0.02);
+        let series: Complex64 = coefficients.iter().rev().fold(Complex64::new(0.0, 0.0), |acc, &ck| acc * h + ck);
+        assert!((series - m.apply(z0 + h)).norm() < 1e-12);
+
+        // 1/(2 + h) = Σ (-1)^k h^k / 2^(k+1)
+        let inversion = MobiusTransform::new(
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 0.0),
+        ).unwrap();
+        for (k, ck) in inversion.taylor(Complex64::new(2.0, 0.0), 4).into_iter().enumerate() {
+            let expected = (-1.0_f64).powi(k as i32) / 2.0_f64.powi(k as i32 + 1);
+            assert!((ck - expected).norm() < 1e-12, "c_{} = {}", k, ck);
+        }
+
+        // Invalid at the pole
+        assert!(inversion.taylor(Complex64::new(0.0, 0.0), 2).iter().all(|ck| ck.is_nan()));
+        assert_eq!(inversion.taylor(COMPLEX_INFINITY, 0).len(), 1);
+    }
+
+    #[test]
+    fn test_schwarzian_vanishes() {
         let m = MobiusTransform::new(
             Complex64::new(2.0, 1.0),
             Complex64::new(1.0, 0.0),
             Complex64::new(1.0, 1.0),
             Complex64::new(3.0, 0.0),
         ).unwrap();
-        let inv = m.inverse();
-        let z = Complex64::new(2.0, 3.0);
-        
-        let result = inv.apply(m.apply(z));
-        assert!((result - z).norm() < 1e-10);
+        let points = [
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, -2.0),
+            Complex64::new(-3.5, 0.25),
+            Complex64::new(100.0, 50.0),
+            COMPLEX_INFINITY,
+        ];
+        for z in points {
+            assert!(m.schwarzian(z).norm() < 1e-8, "S(f)({}) = {}", z, m.schwarzian(z));
+        }
     }
-    
+
     #[test]
-    fn test_apply_infinity_when_c_nonzero_a_nonzero() {
-        // f(z) = (2z + 1) / (z + 1)
-        // c ≠ 0 && a ≠ 0, so f(∞) = a/c = 2/1 = 2
-        let m = MobiusTransform::new(
+    fn test_interpolate_endpoints() {
+        let m1 = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let m2 = MobiusTransform::new(
+            Complex64::new(0.0, 1.0),
             Complex64::new(2.0, 0.0),
+            Complex64::new(-1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+        ).unwrap();
+
+        assert!(m1.interpolate(&m2, 0.0).unwrap().approx_eq(&m1));
+        assert!(m1.interpolate(&m2, 1.0).unwrap().approx_eq(&m2));
+
+        // Constant speed along the path
+        let mid = m1.interpolate(&m2, 0.5).unwrap();
+        assert!((m1.distance(&mid) - mid.distance(&m2)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_interpolate_to_parabolic() {
+        let p = Complex64::new(1.0, 1.0);
+        let tau = Complex64::new(2.0, -1.0);
+        let target = MobiusTransform::parabolic(p, tau).unwrap();
+        let identity = MobiusTransform::identity();
+
+        assert!(identity.interpolate(&target, 0.0).unwrap().approx_eq(&identity));
+        assert!(identity.interpolate(&target, 1.0).unwrap().approx_eq(&target));
+
+        // Every intermediate map is the parabolic map with the same fixed point,
+        // and the path moves continuously at constant speed
+        let steps = 50;
+        let mut previous = identity;
+        let mut speed = None;
+        for k in 1..=steps {
+            let t = k as f64 / steps as f64;
+            let current = identity.interpolate(&target, t).unwrap();
+            assert!(current.approx_eq(&MobiusTransform::parabolic(p, tau * t).unwrap()));
+            assert!((current.apply(p) - p).norm() < 1e-10);
+
+            let step = previous.distance(&current);
+            let expected = *speed.get_or_insert(step);
+            assert!((step - expected).abs() < 1e-8);
+            previous = current;
+        }
+
+        // A parabolic map fixing infinity is a translation
+        let translation = MobiusTransform::translation(tau).unwrap();
+        let quarter = identity.interpolate(&translation, 0.25).unwrap();
+        assert!(quarter.approx_eq(&MobiusTransform::translation(tau * 0.25).unwrap()));
+    }
+
+    /// Returns the summed chordal length of the paths of `points` as `path(t)` moves
+    /// from t = 0 to t = 1.
+    fn traveled(path: impl Fn(f64) -> MobiusTransform, points: &[Complex64]) -> f64 {
+        let steps = 200;
+        (1..=steps)
+            .map(|k| {
+                let before = path((k - 1) as f64 / steps as f64);
+                let after = path(k as f64 / steps as f64);
+                points
+                    .iter()
+                    .map(|&z| chordal_distance(before.apply(z), after.apply(z)))
+                    .sum::<f64>()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_interpolate_shortest_near_half_turn() {
+        // Multiplier -2: the relative map is a half-turn loxodromic, so the two
+        // branches of the logarithm rotate by π in opposite directions
+        let start = MobiusTransform::identity();
+        let end = MobiusTransform::from_fixed_points_and_multiplier(
+            Complex64::new(0.5, -0.5),
+            Complex64::new(3.0, 0.0),
+            Complex64::new(-2.0, 0.0),
+        ).unwrap();
+
+        for t in [0.0, 1.0] {
+            assert!(start.interpolate_shortest(&end, t).unwrap().approx_eq(&start.interpolate(&end, t).unwrap()));
+        }
+        // The probe points of the heuristic
+        let points = [
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(-1.0, 0.0),
+            Complex64::new(0.0, 1.0),
+            Complex64::new(0.0, -1.0),
+            COMPLEX_INFINITY,
+        ];
+        let naive = traveled(|t| start.interpolate(&end, t).unwrap(), &points);
+        let shortest = traveled(|t| start.interpolate_shortest(&end, t).unwrap(), &points);
+        assert!(shortest < naive - 0.5, "shortest {} >= naive {}", shortest, naive);
+    }
+
+    #[test]
+    fn test_interpolate_shortest_matches_interpolate_away_from_half_turns() {
+        let m1 = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let parabolic = MobiusTransform::parabolic(Complex64::new(1.0, 1.0), Complex64::new(2.0, -1.0)).unwrap();
+        for other in [MobiusTransform::rotation(2.0).unwrap(), parabolic] {
+            for t in [0.25, 0.5, 1.0] {
+                assert!(m1.interpolate_shortest(&other, t).unwrap().approx_eq(&m1.interpolate(&other, t).unwrap()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_apply_homogeneous() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
             Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let one = Complex64::new(1.0, 0.0);
+        for z in [Complex64::new(0.0, 0.0), Complex64::new(1.5, -2.0), Complex64::new(-0.3, 0.7)] {
+            let (numerator, denominator) = m.apply_homogeneous(z, one);
+            assert!((numerator / denominator - m.apply(z)).norm() < 1e-12);
+
+            // Any representative of the same point gives the same quotient
+            let scale = Complex64::new(-2.0, 0.5);
+            let (numerator, denominator) = m.apply_homogeneous(z * scale, scale);
+            assert!((numerator / denominator - m.apply(z)).norm() < 1e-12);
+        }
+
+        // Infinity is [1 : 0], and the pole maps to a zero second coordinate
+        let (numerator, denominator) = m.apply_homogeneous(one, Complex64::new(0.0, 0.0));
+        assert!((numerator / denominator - m.apply(COMPLEX_INFINITY)).norm() < 1e-12);
+        let (_, denominator) = m.apply_homogeneous(m.pole(), one);
+        assert!(denominator.norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_mean() {
+        let m1 = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
             Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let m2 = m1.compose(&MobiusTransform::elliptic(Complex64::new(0.5, 0.5), 1.2).unwrap());
+
+        assert!(MobiusTransform::mean(&[m1]).unwrap().approx_eq(&m1));
+        assert!(MobiusTransform::mean(&[m1, m1]).unwrap().approx_eq(&m1));
+
+        // The mean of two lies halfway between them
+        let mean = MobiusTransform::mean(&[m1, m2]).unwrap();
+        let (to_first, to_second) = (m1.distance(&mean), mean.distance(&m2));
+        assert!((to_first - to_second).abs() < 1e-9);
+        assert!((to_first + to_second - m1.distance(&m2)).abs() < 1e-9);
+        assert!(mean.approx_eq_with_tolerance(&m1.interpolate(&m2, 0.5).unwrap(), 1e-9));
+
+        // Order does not matter, and the average logarithm vanishes at the mean
+        let m3 = MobiusTransform::translation(Complex64::new(0.3, -0.2)).unwrap().compose(&m1);
+        let mean = MobiusTransform::mean(&[m1, m2, m3]).unwrap();
+        assert!(mean.approx_eq_with_tolerance(&MobiusTransform::mean(&[m3, m1, m2]).unwrap(), 1e-9));
+        let mut sum = [[Complex64::new(0.0, 0.0); 2]; 2];
+        for m in [m1, m2, m3] {
+            let x = sl2::log(&sl2::with_positive_trace(&m.compose(&mean.inverse()).normalized_matrix()));
+            for i in 0..2 {
+                for j in 0..2 {
+                    sum[i][j] += x[i][j];
+                }
+            }
+        }
+        assert!(sl2::frobenius_norm(&sum) < 1e-9);
+
+        assert!(matches!(MobiusTransform::mean(&[]), Err(TransformError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_fraction() {
+        let loxodromic = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
             Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
         ).unwrap();
-        let result = m.apply(COMPLEX_INFINITY);
-        assert!((result - Complex64::new(2.0, 0.0)).norm() < 1e-10);
+
+        assert!(loxodromic.fraction(0.0).unwrap().approx_eq(&MobiusTransform::identity()));
+        assert!(loxodromic.fraction(1.0).unwrap().approx_eq(&loxodromic));
+
+        let half = loxodromic.fraction(0.5).unwrap();
+        assert!(half.compose(&half).approx_eq(&loxodromic));
+        assert!(half.approx_eq(&loxodromic.sqrt()));
+
+        // Extrapolation
+        assert!(loxodromic.fraction(2.0).unwrap().approx_eq(&loxodromic.compose(&loxodromic)));
+        assert!(loxodromic.fraction(-1.0).unwrap().approx_eq(&loxodromic.inverse()));
     }
-    
+
     #[test]
-    fn test_apply_infinity_when_c_nonzero_a_zero() {
-        // f(z) = 1 / (z + 1) (with a=0, c≠0)
-        // c ≠ 0 && a == 0, so f(∞) = 0
+    fn test_flow_group_law() {
+        let loxodromic = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let parabolic = MobiusTransform::parabolic(Complex64::new(1.0, -1.0), Complex64::new(0.5, 2.0)).unwrap();
+        let elliptic = MobiusTransform::elliptic(Complex64::new(0.5, 0.5), 2.0).unwrap();
+
+        for m in [loxodromic, parabolic, elliptic] {
+            assert!(m.flow(0.0).unwrap().approx_eq(&MobiusTransform::identity()));
+            assert!(m.flow(1.0).unwrap().approx_eq(&m));
+            assert!(m.flow(0.3).unwrap().approx_eq(&m.fraction(0.3).unwrap()));
+            for (a, b) in [(0.25, 0.5), (1.0, 1.0), (-0.7, 0.2), (1.5, -2.5), (3.0, 0.125)] {
+                let product = m.flow(a).unwrap().compose(&m.flow(b).unwrap());
+                assert!(product.approx_eq_with_tolerance(&m.flow(a + b).unwrap(), 1e-9), "a = {}, b = {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_spiral_path_passes_through_iterates() {
+        // Loxodromic with fixed points 0 and ∞: z ↦ (1.2 + 0.5i) z
+        let m = MobiusTransform::from_affine(Complex64::new(1.2, 0.5), Complex64::new(0.0, 0.0)).unwrap();
+        let z0 = Complex64::new(1.0, 0.5);
+        let path = m.spiral_path(z0, 0.0, 3.0, 30);
+        assert_eq!(path.len(), 31);
+
+        let mut iterate = z0;
+        for n in 0..=3 {
+            assert!((path[10 * n] - iterate).norm() < 1e-10, "t = {}", n);
+            iterate = m.apply(iterate);
+        }
+
+        // Between iterates the path follows the flow
+        assert!((path[5] - m.flow(0.5).unwrap().apply(z0)).norm() < 1e-10);
+
+        // Backwards in time, through the inverse
+        let path = m.spiral_path(z0, 0.0, -1.0, 4);
+        assert!((path[4] - m.inverse().apply(z0)).norm() < 1e-10);
+        assert_eq!(m.spiral_path(z0, 2.0, 5.0, 0).len(), 1);
+    }
+
+    #[test]
+    fn test_fraction_of_translation() {
+        let t = Complex64::new(2.0, -1.0);
+        let translation = MobiusTransform::translation(t).unwrap();
+        let third = MobiusTransform::translation(t / 3.0).unwrap();
+        assert!(translation.fraction(1.0 / 3.0).unwrap().approx_eq(&third));
+    }
+
+    #[test]
+    fn test_is_valid() {
         let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        assert!(m.is_valid());
+        assert!(m.compose(&m.inverse()).is_valid());
+
+        // Tiny coefficients are fine as long as the rows are independent
+        let tiny = MobiusTransform::new_checked(
+            Complex64::new(1e-9, 0.0),
             Complex64::new(0.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1e-9, 0.0),
+        ).unwrap().0;
+        assert!(tiny.is_valid());
+
+        // Nearly proportional rows: accepted by new_checked, but not usable
+        let (nearly_singular, _) = MobiusTransform::new_checked(
             Complex64::new(1.0, 0.0),
             Complex64::new(1.0, 0.0),
             Complex64::new(1.0, 0.0),
+            Complex64::new(1.0 + 1e-13, 0.0),
         ).unwrap();
-        let result = m.apply(COMPLEX_INFINITY);
-        assert_eq!(result, Complex64::new(0.0, 0.0));
+        assert!(!nearly_singular.is_valid());
+
+        // Degenerate matrices are rejected by from_matrix, so build them directly
+        let zero = Complex64::new(0.0, 0.0);
+        let matrix = Array2::from_shape_vec(
+            (2, 2),
+            vec![1.0, 2.0, 2.0, 4.0].into_iter().map(|x| Complex64::new(x, 0.0)).collect(),
+        ).unwrap();
+        assert!(MobiusTransform::from_matrix(&matrix).is_err());
+        let singular = MobiusTransform { a: matrix[[0, 0]], b: matrix[[0, 1]], c: matrix[[1, 0]], d: matrix[[1, 1]] };
+        assert!(!singular.is_valid());
+        assert!(!MobiusTransform { a: zero, b: zero, c: zero, d: zero }.is_valid());
+        let nan = Complex64::new(f64::NAN, 0.0);
+        assert!(!MobiusTransform { a: nan, ..MobiusTransform::identity() }.is_valid());
+        assert!(!MobiusTransform { b: COMPLEX_INFINITY, ..MobiusTransform::identity() }.is_valid());
     }
-    
+
     #[test]
-    fn test_apply_infinity_when_c_zero_a_nonzero() {
-        // f(z) = 2z + 1 (with c=0, a≠0)
-        // c == 0 && a ≠ 0, so f(∞) = ∞
+    fn test_pole_and_image_of_infinity() {
+        // f(z) = 1/z swaps 0 and infinity
         let m = MobiusTransform::new(
-            Complex64::new(2.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 0.0),
             Complex64::new(1.0, 0.0),
             Complex64::new(0.0, 0.0),
+        ).unwrap();
+        assert!(m.pole().norm() < 1e-10);
+        assert!(m.image_of_infinity().norm() < 1e-10);
+
+        // f(z) = (2z + 1) / (z + 3)
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 0.0),
             Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(3.0, 0.0),
         ).unwrap();
-        let result = m.apply(COMPLEX_INFINITY);
-        assert!(is_infinity(result));
+        assert!((m.pole() - Complex64::new(-3.0, 0.0)).norm() < 1e-10);
+        assert!(is_infinity(m.apply(m.pole())));
+        assert!((m.image_of_infinity() - Complex64::new(2.0, 0.0)).norm() < 1e-10);
+        assert!((m.image_of_infinity() - m.apply(COMPLEX_INFINITY)).norm() < 1e-10);
+
+        // Affine maps fix infinity
+        let affine = MobiusTransform::from_affine(Complex64::new(2.0, 1.0), Complex64::new(1.0, 0.0)).unwrap();
+        assert!(is_infinity(affine.pole()));
+        assert!(is_infinity(affine.image_of_infinity()));
     }
-    
+
     #[test]
-    fn test_apply_maps_to_infinity() {
-        // f(z) = 1/z
-        // f(0) = ∞
+    fn test_standard_triple_image() {
+        let zero = Complex64::new(0.0, 0.0);
+        let one = Complex64::new(1.0, 0.0);
+        let (f0, f1, f_infinity) = MobiusTransform::new(zero, one, one, zero).unwrap().standard_triple_image();
+        assert!(is_infinity(f0));
+        assert!((f1 - one).norm() < 1e-12);
+        assert!(f_infinity.norm() < 1e-12);
+
+        let (f0, f1, f_infinity) = MobiusTransform::identity().standard_triple_image();
+        assert_eq!((f0, f1), (zero, one));
+        assert!(is_infinity(f_infinity));
+    }
+
+    #[test]
+    fn test_isometric_circle_of_inversion() {
+        // f(z) = 1/z has isometric circle |z| = 1
         let m = MobiusTransform::new(
             Complex64::new(0.0, 0.0),
             Complex64::new(1.0, 0.0),
             Complex64::new(1.0, 0.0),
             Complex64::new(0.0, 0.0),
         ).unwrap();
-        let result = m.apply(Complex64::new(0.0, 0.0));
-        assert!(is_infinity(result));
+        let (center, radius) = m.isometric_circle().unwrap();
+        assert!(center.norm() < 1e-10);
+        assert!((radius - 1.0).abs() < 1e-10);
+
+        let (center, radius) = m.isometric_circle_of_inverse().unwrap();
+        assert!(center.norm() < 1e-10);
+        assert!((radius - 1.0).abs() < 1e-10);
     }
 
     #[test]
-    fn test_zero_determinant() {
-        let result = MobiusTransform::new(
+    fn test_isometric_circle_is_scale_invariant() {
+        // f(z) = (2z + 1) / (z + 1) with coefficients scaled by 3
+        let m = MobiusTransform::new(
+            Complex64::new(6.0, 0.0),
+            Complex64::new(3.0, 0.0),
+            Complex64::new(3.0, 0.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        let (center, radius) = m.isometric_circle().unwrap();
+        assert!((center - Complex64::new(-1.0, 0.0)).norm() < 1e-10);
+        assert!((radius - 1.0).abs() < 1e-10);
+
+        // The inverse's circle is centered at the image of infinity, a/c = 2
+        let (center, radius) = m.isometric_circle_of_inverse().unwrap();
+        assert!((center - Complex64::new(2.0, 0.0)).norm() < 1e-10);
+        assert!((radius - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_isometric_circle_affine() {
+        let m = MobiusTransform::from_affine(Complex64::new(2.0, 1.0), Complex64::new(1.0, 0.0)).unwrap();
+        assert!(m.isometric_circle().is_none());
+        assert!(m.isometric_circle_of_inverse().is_none());
+    }
+
+    #[test]
+    fn test_coeff_string_round_trip() {
+        let m = MobiusTransform::new(
+            Complex64::new(2.0, 1.0),
             Complex64::new(1.0, 0.0),
-            Complex64::new(2.0, 0.0),
-            Complex64::new(2.0, 0.0),
-            Complex64::new(4.0, 0.0),
-        );
-        assert!(result.is_err());
+            Complex64::new(1.0, 1.0),
+            Complex64::new(3.0, 0.0),
+        ).unwrap();
+        assert_eq!(m.to_coeff_string(), "a=2+1i;b=1+0i;c=1+1i;d=3+0i");
+
+        let m = MobiusTransform::new(
+            Complex64::new(0.1, -1e-20),
+            Complex64::new(-2.5e15, 1.0 / 3.0),
+            Complex64::new(-0.0, -0.0),
+            Complex64::new(1.0, 7.0),
+        ).unwrap();
+        let round_trip = MobiusTransform::from_coeff_string(&m.to_coeff_string()).unwrap();
+        assert_eq!(round_trip.to_matrix(), m.to_matrix());
+    }
+
+    #[test]
+    fn test_from_coeff_string_whitespace_and_order() {
+        let m = MobiusTransform::from_coeff_string(" d=3+0i; c = 1+1i;b=1+0i ;a=2+1i ").unwrap();
+        assert_eq!(m.to_coeff_string(), "a=2+1i;b=1+0i;c=1+1i;d=3+0i");
+    }
+
+    #[test]
+    fn test_from_coeff_string_malformed() {
+        let malformed = [
+            "",
+            "a=2+1i;b=1+0i;c=1+1i",
+            "a=2+1i;b=1+0i;c=1+1i;d=3+0i;a=1+0i",
+            "a=2+1i;b=1+0i;c=1+1i;e=3+0i",
+            "a=2+1;b=1+0i;c=1+1i;d=3+0i",
+            "a=2+xi;b=1+0i;c=1+1i;d=3+0i",
+            "a:2+1i;b=1+0i;c=1+1i;d=3+0i",
+        ];
+        for s in malformed {
+            let result = MobiusTransform::from_coeff_string(s);
+            assert!(matches!(result, Err(TransformError::InvalidFormat(_))), "{:?} parsed", s);
+        }
+
+        // Well-formed but singular coefficients are rejected by validation
+        let result = MobiusTransform::from_coeff_string("a=1+0i;b=2+0i;c=2+0i;d=4+0i");
         assert_eq!(result.unwrap_err(), TransformError::SingularTransform);
     }
-    
+
     #[test]
-    fn test_infinite_coefficient() {
-        let result = MobiusTransform::new(
-            COMPLEX_INFINITY,
+    fn test_then_scale_and_pre_scale() {
+        // f(z) = (z - 1) / (z + 2i)
+        let f = MobiusTransform::new(
             Complex64::new(1.0, 0.0),
-            Complex64::new(0.0, 0.0),
+            Complex64::new(-1.0, 0.0),
             Complex64::new(1.0, 0.0),
-        );
-        assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), TransformError::InfiniteCoefficient);
+            Complex64::new(0.0, 2.0),
+        ).unwrap();
+        let k = Complex64::new(-1.5, 2.0);
+        let (then, pre) = (f.then_scale(k).unwrap(), f.pre_scale(k).unwrap());
+        for z in [Complex64::new(0.3, -0.4), Complex64::new(5.0, 1.0), Complex64::new(0.0, 0.0)] {
+            assert!((then.apply(z) - k * f.apply(z)).norm() < 1e-12);
+            assert!((pre.apply(z) - f.apply(k * z)).norm() < 1e-12);
+        }
+        assert!(is_infinity(then.apply(Complex64::new(0.0, -2.0))));
+        assert!((then.apply(COMPLEX_INFINITY) - k).norm() < 1e-12);
+        assert!(f.then_scale(Complex64::new(1.0, 0.0)).unwrap().approx_eq(&f));
+
+        assert_eq!(f.then_scale(Complex64::new(0.0, 0.0)).unwrap_err(), TransformError::SingularTransform);
+        assert_eq!(f.pre_scale(Complex64::new(f64::NAN, 0.0)).unwrap_err(), TransformError::InfiniteCoefficient);
+    }
+
+    #[test]
+    fn test_is_identity() {
+        assert!(MobiusTransform::identity().is_identity(0.0));
+        let zero = Complex64::new(0.0, 0.0);
+        let five = Complex64::new(5.0, 0.0);
+        assert!(MobiusTransform::new(five, zero, zero, five).unwrap().is_identity(1e-12));
+        assert!(MobiusTransform::new(-five, zero, zero, -five).unwrap().is_identity(1e-12));
+
+        // The round trip has coefficients det(m) · I up to rounding, not those of identity()
+        let m = MobiusTransform::new(
+            Complex64::new(0.3, 1.7),
+            Complex64::new(-2.0, 0.1),
+            Complex64::new(1.1, -0.4),
+            Complex64::new(0.9, 2.2),
+        ).unwrap();
+        let round_trip = m.inverse().compose(&m);
+        assert_ne!(round_trip.to_array(), MobiusTransform::identity().to_array());
+        assert!(round_trip.is_identity(1e-12));
+
+        assert!(!m.is_identity(1e-12));
+        let near = MobiusTransform::translation(Complex64::new(1e-6, 0.0)).unwrap();
+        assert!(!near.is_identity(1e-9));
+        assert!(near.is_identity(1e-5));
+    }
+
+    #[test]
+    fn test_flow_large_and_non_finite_time() {
+        let tripling = MobiusTransform::from_affine(Complex64::new(3.0, 0.0), Complex64::new(0.0, 0.0)).unwrap();
+        let z = Complex64::new(1.0, 0.0);
+        // 3^300 ≈ 10^143, with coefficients (1, 0, 0, 3^-300)
+        let image = tripling.flow(300.0).unwrap().apply_with_tolerance(z, 0.0);
+        assert!((image.re.ln() - 300.0 * 3.0_f64.ln()).abs() < 1e-9 && image.im.abs() < 1e-9 * image.re);
+        assert!(tripling.flow(-600.0).is_ok());
+
+        for t in [10000.0, -10000.0] {
+            assert_eq!(tripling.flow(t).unwrap_err(), TransformError::SingularTransform, "t = {}", t);
+        }
+        for t in [f64::INFINITY, f64::NAN] {
+            assert_eq!(tripling.flow(t).unwrap_err(), TransformError::InfiniteCoefficient, "t = {}", t);
+        }
+
+        // Elliptic flows stay bounded for any finite time
+        let rotation = MobiusTransform::elliptic(Complex64::new(0.5, 0.5), 2.0).unwrap();
+        assert!(rotation.flow(1e6).unwrap().is_valid());
+    }
+
+    #[test]
+    fn test_interpolate_shortest_large_and_non_finite_time() {
+        let end = MobiusTransform::from_fixed_points_and_multiplier(
+            Complex64::new(0.5, -0.5),
+            Complex64::new(3.0, 0.0),
+            Complex64::new(-2.0, 0.0),
+        ).unwrap();
+        let start = MobiusTransform::identity();
+        assert!(start.interpolate_shortest(&end, 2.0).unwrap().approx_eq_with_tolerance(&end.compose(&end), 1e-9));
+
+        // The multiplier at 3 is -1/2, so for large t every other point is drawn to 3
+        for t in [50.0, 1e5] {
+            match start.interpolate_shortest(&end, t) {
+                Ok(far) => assert!(chordal_distance(far.apply(Complex64::new(0.0, 0.0)), Complex64::new(3.0, 0.0)) < 1e-9),
+                Err(error) => assert_eq!(error, TransformError::SingularTransform),
+            }
+        }
+        assert_eq!(start.interpolate_shortest(&end, f64::NAN).unwrap_err(), TransformError::InfiniteCoefficient);
+    }
+
+    #[test]
+    fn test_interpolate_large_and_non_finite_time() {
+        let tripling = MobiusTransform::from_affine(Complex64::new(3.0, 0.0), Complex64::new(0.0, 0.0)).unwrap();
+        let identity = MobiusTransform::identity();
+        let z = Complex64::new(1.0, 0.0);
+        let image = tripling.fraction(300.0).unwrap().apply_with_tolerance(z, 0.0);
+        assert!((image.re.ln() - 300.0 * 3.0_f64.ln()).abs() < 1e-9);
+        assert!(identity.interpolate(&tripling, -300.0).unwrap().apply(z).norm() < 1e-140);
+
+        for t in [10000.0, -10000.0] {
+            assert_eq!(tripling.fraction(t).unwrap_err(), TransformError::SingularTransform);
+            assert_eq!(identity.interpolate(&tripling, t).unwrap_err(), TransformError::SingularTransform);
+        }
+        assert_eq!(tripling.fraction(f64::NAN).unwrap_err(), TransformError::InfiniteCoefficient);
+        assert_eq!(identity.interpolate(&tripling, f64::INFINITY).unwrap_err(), TransformError::InfiniteCoefficient);
+
+        // Parabolic paths grow only linearly
+        let translation = MobiusTransform::translation(Complex64::new(1.0, 0.0)).unwrap();
+        assert!((translation.fraction(1e6).unwrap().apply(z) - Complex64::new(1e6 + 1.0, 0.0)).norm() < 1e-6);
+        assert!(translation.fraction(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_spiral_path_large_and_non_finite_time() {
+        let tripling = MobiusTransform::from_affine(Complex64::new(3.0, 0.0), Complex64::new(0.0, 0.0)).unwrap();
+        let z0 = Complex64::new(1.0, 0.0);
+        let path = tripling.spiral_path(z0, 0.0, 10000.0, 4);
+        assert!((path[0] - z0).norm() < 1e-12);
+        assert!(path[1..].iter().all(|&z| is_infinity(z)));
+        assert!(tripling.spiral_path(z0, 0.0, -10000.0, 2)[2].norm() < 1e-300);
+
+        // The multiplier at 3 is -1/2, so the path from 0 is drawn to 3
+        let m = MobiusTransform::from_fixed_points_and_multiplier(
+            Complex64::new(0.5, -0.5),
+            Complex64::new(3.0, 0.0),
+            Complex64::new(-2.0, 0.0),
+        ).unwrap();
+        let path = m.spiral_path(Complex64::new(0.0, 0.0), 0.0, 10000.0, 10);
+        assert!(path[1..].iter().all(|&z| chordal_distance(z, Complex64::new(3.0, 0.0)) < 1e-9));
+        let path = m.spiral_path(Complex64::new(0.0, 0.0), 0.0, -10000.0, 10);
+        assert!(chordal_distance(path[10], Complex64::new(0.5, -0.5)) < 1e-9);
+
+        assert!(m.spiral_path(z0, 0.0, f64::NAN, 1)[1].is_nan());
+    }
+
+    #[test]
+    fn test_mean_of_widely_spread_maps() {
+        // The logarithms are large, and the steps are built without overflow
+        let scaling = MobiusTransform::from_affine(Complex64::new(1e50, 0.0), Complex64::new(0.0, 0.0)).unwrap();
+        let mean = MobiusTransform::mean(&[scaling, scaling.inverse()]).unwrap();
+        assert!(mean.approx_eq_with_tolerance(&MobiusTransform::identity(), 1e-9));
+    }
+
+    #[test]
+    fn test_to_affine_of_rescaled_map() {
+        // Scaling the coefficients down does not make z ↦ z / (5·10⁻⁷ z + 1) affine
+        let small = Complex64::new(1e-4, 0.0);
+        let m = MobiusTransform::new(small, Complex64::new(0.0, 0.0), Complex64::new(5e-11, 0.0), small).unwrap();
+        assert!(m.to_affine().is_none());
+        assert!(!is_infinity(m.pole()));
+
+        let affine = MobiusTransform::new(small, Complex64::new(2e-4, 0.0), Complex64::new(0.0, 0.0), small * 2.0).unwrap();
+        let (scale_rot, translation) = affine.to_affine().unwrap();
+        assert!((scale_rot - Complex64::new(0.5, 0.0)).norm() < 1e-12);
+        assert!((translation - Complex64::new(1.0, 0.0)).norm() < 1e-12);
     }
 }